@@ -114,9 +114,63 @@ fn get_rust_srcfile(filepath: &str) -> String {
     contents
 }
 
+/// Numeric type whose inherent methods are exposed as builtins
+struct NumType {
+    /// Rust primitive type name
+    pub rust_type: &'static str,
+    /// Name of the `Value` and `ValueType` variant holding this type
+    pub variant: &'static str,
+    /// Prepended to the builtin names, e.g. `signed::`
+    pub name_prefix: &'static str,
+    /// Rust source file, relative to sysroot
+    pub src_file: &'static str,
+    /// Start marker of the impl block in the source
+    pub src_start: &'static str,
+    /// End marker of the impl block in the source
+    pub src_until: &'static str,
+    /// Functions that panic on overflow, called through their `checked_`
+    /// form so that they fail with `Error::IntegerOverflow` instead
+    pub checked_only: &'static [&'static str],
+    pub target_file: &'static str,
+}
+
+const NUM_TYPES: [NumType; 3] = [
+    NumType {
+        rust_type: "u64",
+        variant: "Integer",
+        name_prefix: "",
+        src_file: "/lib/rustlib/src/rust/src/libcore/num/mod.rs",
+        src_start: "uint_impl",
+        src_until: "impl u8 {",
+        checked_only: &[],
+        target_file: "src/builtins/generated/int.rs",
+    },
+    NumType {
+        rust_type: "i64",
+        variant: "SignedInteger",
+        name_prefix: "signed::",
+        src_file: "/lib/rustlib/src/rust/src/libcore/num/mod.rs",
+        src_start: "macro_rules! int_impl",
+        src_until: "impl i8 {",
+        checked_only: &["abs", "div_euc", "mod_euc"],
+        target_file: "src/builtins/generated/signed.rs",
+    },
+    NumType {
+        rust_type: "f64",
+        variant: "Float",
+        name_prefix: "float::",
+        src_file: "/lib/rustlib/src/rust/src/libstd/f64.rs",
+        src_start: "impl f64 {",
+        src_until: "#[cfg(test)]",
+        checked_only: &[],
+        target_file: "src/builtins/generated/float.rs",
+    },
+];
+
 #[derive(Debug)]
 struct Function {
     pub name: String,
+    pub self_type: String,
     pub arg_types: Vec<String>,
     pub ret_type: String,
 }
@@ -125,7 +179,7 @@ impl Function {
         self.arg_types.len() + 1
     }
     pub fn fmt_signature(&self) -> String {
-        let mut argtypes = vec!["u64".to_owned()];
+        let mut argtypes = vec![self.self_type.clone()];
         for at in &self.arg_types {
             argtypes.push(at.clone());
         }
@@ -134,9 +188,9 @@ impl Function {
     }
 }
 
-fn get_functions(src_text: &str) -> Vec<Function> {
-    let start = src_text.find("uint_impl").unwrap();
-    let until = src_text.find("impl u8 {").unwrap();
+fn get_functions(src_text: &str, num_type: &NumType) -> Vec<Function> {
+    let start = src_text.find(num_type.src_start).unwrap();
+    let until = src_text.find(num_type.src_until).unwrap();
 
     let re = Regex::new(RE_RUST_FN).unwrap();
 
@@ -180,6 +234,7 @@ fn get_functions(src_text: &str) -> Vec<Function> {
 
             result.push(Function {
                 name: name.to_owned(),
+                self_type: num_type.rust_type.to_owned(),
                 arg_types,
                 ret_type,
             })
@@ -188,10 +243,20 @@ fn get_functions(src_text: &str) -> Vec<Function> {
     result
 }
 
-fn type_checked_start(argindex: usize) -> String {
+/// `Value` variant used to pass an argument of the given Rust type
+fn value_variant(rust_type: &str) -> &'static str {
+    match rust_type {
+        "u64" | "u32" => "Integer",
+        "i64" | "i32" => "SignedInteger",
+        "f64" => "Float",
+        _ => panic!("Invalid arg type"),
+    }
+}
+
+fn type_checked_start(argindex: usize, variant: &str) -> String {
     format!(
-        "if let Value::Integer(a{}) = arg{} {{\n",
-        argindex, argindex
+        "if let Value::{}(a{}) = arg{} {{\n",
+        variant, argindex, argindex
     )
 }
 
@@ -207,8 +272,8 @@ fn type_checked_end(argindex: usize, required_type: &str) -> String {
     r_text
 }
 
-fn generate_int_functions(src_text: &str) -> String {
-    let src_text: String = src_text.replace("Self", "u64");
+fn generate_functions(src_text: &str, num_type: &NumType) -> String {
+    let src_text: String = src_text.replace("Self", num_type.rust_type);
 
     // header block
     let mut file_text: String = "// !!! DO NOT EDIT !!!\n".to_owned();
@@ -225,7 +290,7 @@ fn generate_int_functions(src_text: &str) -> String {
         use crate::value::{BuiltinFunction, Value, ValueType};\n\n",
     );
 
-    let functions = get_functions(src_text.as_ref());
+    let functions = get_functions(src_text.as_ref(), num_type);
 
     for f in &functions {
         // doc comment
@@ -240,18 +305,20 @@ fn generate_int_functions(src_text: &str) -> String {
         }
 
         // type_check
-        fn_text.push_str(&type_checked_start(0));
+        fn_text.push_str(&type_checked_start(0, num_type.variant));
         for i in (1..f.argc()).rev() {
-            fn_text.push_str(&type_checked_start(i));
+            let variant = value_variant(&f.arg_types[i - 1]);
+            fn_text.push_str(&type_checked_start(i, variant));
         }
 
         // convert inputs
         fn_text.push_str("let a0_conv = a0;\n");
         for (i, atype) in f.arg_types.iter().enumerate() {
             let conv_stmt = match atype.as_ref() {
-                "u64" => format!("a{}", i + 1),
-                "u32" => format!(
-                    "u32::try_from(a{}).map_err(|_| Error::IntegerOverflow)?",
+                t if t == num_type.rust_type => format!("a{}", i + 1),
+                "u32" | "i32" => format!(
+                    "{}::try_from(a{}).map_err(|_| Error::IntegerOverflow)?",
+                    atype,
                     i + 1
                 ),
                 _ => panic!("Invalid arg type"),
@@ -261,45 +328,65 @@ fn generate_int_functions(src_text: &str) -> String {
         }
 
         // do the actual operation
-        fn_text.push_str(format!("let r: {} = u64::{}(\n", f.ret_type, f.name).as_ref());
+        let checked = num_type.checked_only.contains(&f.name.as_str());
+        let op_name = if checked {
+            format!("checked_{}", f.name)
+        } else {
+            f.name.clone()
+        };
+        fn_text.push_str(
+            format!(
+                "let r: {} = {}::{}(\n",
+                f.ret_type, num_type.rust_type, op_name
+            ).as_ref(),
+        );
         for i in 0..f.argc() {
             fn_text.push_str(format!("a{}_conv,\n", i).as_ref());
         }
-        fn_text.push_str(");\n");
+        if checked {
+            fn_text.push_str(").ok_or(Error::IntegerOverflow)?;\n");
+        } else {
+            fn_text.push_str(");\n");
+        }
 
         // return type conversion & push
-        fn_text.push_str(match f.ret_type.as_ref() {
-            "u64" => "interp.data.push(Value::Integer(r));\n",
-            "u32" => "interp.data.push(Value::Integer(u64::from(r)));\n",
-            "bool" => "interp.data.push(Value::Boolean(r));\n",
-            "Option<u64>" => {
-                "if let Some(v) = r {
-                    interp.data.push(Value::Integer(v));
-                }
-                interp.data.push(Value::Boolean(r.is_some()));\n"
-            },
-            "(u64, bool)" => {
+        let rt = num_type.rust_type;
+        let variant = num_type.variant;
+        let ret_text = match f.ret_type.as_ref() {
+            t if t == rt => format!("interp.data.push(Value::{}(r));\n", variant),
+            "u32" => "interp.data.push(Value::Integer(u64::from(r)));\n".to_owned(),
+            "bool" => "interp.data.push(Value::Boolean(r));\n".to_owned(),
+            t if t == format!("Option<{}>", rt) => format!(
+                "if let Some(v) = r {{
+                    interp.data.push(Value::{}(v));
+                }}
+                interp.data.push(Value::Boolean(r.is_some()));\n",
+                variant
+            ),
+            t if t == format!("({}, bool)", rt) => format!(
                 "interp.data.push(Value::Boolean(r.1));
-                interp.data.push(Value::Integer(r.0));\n"
-            },
+                interp.data.push(Value::{}(r.0));\n",
+                variant
+            ),
+            t if t == format!("({}, {})", rt, rt) => format!(
+                "interp.data.push(Value::{}(r.0));
+                interp.data.push(Value::{}(r.1));\n",
+                variant, variant
+            ),
             x => {
                 panic!("Unhandled return type: {:?}", x);
             },
-        });
+        };
+        fn_text.push_str(&ret_text);
 
         // ok return
         fn_text.push_str("Ok(())");
 
         // /type_check
         for (i, atype) in f.arg_types.iter().enumerate() {
-            let arg_type = match atype.as_ref() {
-                "u64" => "Integer",
-                "u32" => "Integer",
-                _ => panic!("Invalid arg type"),
-            };
-            fn_text.push_str(&type_checked_end(i + 1, arg_type));
+            fn_text.push_str(&type_checked_end(i + 1, value_variant(atype)));
         }
-        fn_text.push_str(&type_checked_end(0, "Integer"));
+        fn_text.push_str(&type_checked_end(0, num_type.variant));
 
         // end
         fn_text.push_str("}\n\n");
@@ -313,8 +400,8 @@ fn generate_int_functions(src_text: &str) -> String {
     for f in &functions {
         file_text.push_str(
             format!(
-                "interp.register_builtin(BuiltinFunction::new(\"{}\", f_{}));",
-                f.name, f.name,
+                "interp.register_builtin(BuiltinFunction::new(\"{}{}\", f_{}));",
+                num_type.name_prefix, f.name, f.name,
             ).as_ref(),
         );
     }
@@ -324,43 +411,48 @@ fn generate_int_functions(src_text: &str) -> String {
     format_rust(file_text)
 }
 
-fn main() {
-    let target_file_path = "src/builtins/generated/int.rs";
+fn generate_if_changed(src_text: &str, header: Header, num_type: &NumType) {
+    let target_file_path = num_type.target_file;
     let target_header = get_target_file_header(target_file_path);
 
-    let src_text = get_rust_srcfile("/lib/rustlib/src/rust/src/libcore/num/mod.rs");
-    let src_text_bytes: Vec<u8> = src_text.bytes().collect();
-    let rust_src_crc32 = crc::crc32::checksum_ieee(&src_text_bytes);
-
-    let own_text = get_own_file();
-    let own_text_bytes: Vec<u8> = own_text.bytes().collect();
-    let build_rs_crc32 = crc::crc32::checksum_ieee(&own_text_bytes);
-
-    let header = Header {
-        rust_src_crc32,
-        build_rs_crc32,
-        modify_time_st: SystemTime::now(), // just a placeholder
-    };
-
     // check if already ok
     if let Some(th) = target_header {
         if header.eq_crc32s(&th) {
             if let Some(mt) = get_target_file_mtime(target_file_path) {
                 if mt == th.modify_time_st {
-                    println!("Using the existing version");
+                    println!("Using the existing version of {}", target_file_path);
                     return;
                 }
             }
         }
     }
 
-    println!("Generating function code");
+    println!("Generating function code for {}", num_type.rust_type);
 
-    let intf: String = generate_int_functions(src_text.as_ref());
+    let numf: String = generate_functions(src_text, num_type);
 
-    let intf_bytes: Vec<u8> = intf.bytes().collect();
+    let numf_bytes: Vec<u8> = numf.bytes().collect();
     let mut file = File::create(target_file_path).unwrap();
-    file.write_all(intf_bytes.as_slice()).unwrap();
+    file.write_all(numf_bytes.as_slice()).unwrap();
 
     set_target_file_header(target_file_path, header);
 }
+
+fn main() {
+    let own_text = get_own_file();
+    let own_text_bytes: Vec<u8> = own_text.bytes().collect();
+    let build_rs_crc32 = crc::crc32::checksum_ieee(&own_text_bytes);
+
+    for num_type in &NUM_TYPES {
+        let src_text = get_rust_srcfile(num_type.src_file);
+        let src_text_bytes: Vec<u8> = src_text.bytes().collect();
+        let rust_src_crc32 = crc::crc32::checksum_ieee(&src_text_bytes);
+
+        let header = Header {
+            rust_src_crc32,
+            build_rs_crc32,
+            modify_time_st: SystemTime::now(), // just a placeholder
+        };
+        generate_if_changed(src_text.as_ref(), header, num_type);
+    }
+}
@@ -0,0 +1,12 @@
+#!/bin/sh
+# Checks that `--check` reports the diagnostics in the `.out` file next to
+# each input here. Set HEPTA to use an already built interpreter.
+cd "$(dirname "$0")/../.." || exit 1
+HEPTA=${HEPTA:-"cargo run -q --"}
+status=0
+for input in examples/syntax_errors/*.h7; do
+    if ! $HEPTA --check "$input" | diff -u "${input%.h7}.out" -; then
+        status=1
+    fi
+done
+exit $status
@@ -0,0 +1,119 @@
+//! Helpers for popping typed arguments off the data stack
+
+use std::convert::TryFrom;
+
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::value::{Value, ValueMap, ValueType};
+
+/// Fails before anything is popped if fewer than `n` values are available
+pub fn require(interp: &Interpreter, n: usize) -> Result<(), Error> {
+    if interp.data.len() < n {
+        Err(Error::NotEnoughArguments(n, interp.data.len()))
+    } else {
+        Ok(())
+    }
+}
+
+pub fn pop(interp: &mut Interpreter) -> Result<Value, Error> {
+    interp.data.pop().ok_or(Error::StackUndeflow)
+}
+
+pub fn pop_boolean(interp: &mut Interpreter) -> Result<bool, Error> {
+    match pop(interp)? {
+        Value::Boolean(v) => Ok(v),
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::Boolean],
+        )),
+    }
+}
+
+pub fn pop_integer(interp: &mut Interpreter) -> Result<u64, Error> {
+    match pop(interp)? {
+        Value::Integer(v) => Ok(v),
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::Integer],
+        )),
+    }
+}
+
+/// Pops an integer and converts it to `usize`
+pub fn pop_usize(interp: &mut Interpreter) -> Result<usize, Error> {
+    let v = pop_integer(interp)?;
+    usize::try_from(v).map_err(|_| Error::IntegerOverflow)
+}
+
+/// Pops an index, given either as an `Index` or an `Integer`
+pub fn pop_index(interp: &mut Interpreter) -> Result<usize, Error> {
+    match pop(interp)? {
+        Value::Index(v) => Ok(v),
+        Value::Integer(v) => usize::try_from(v).map_err(|_| Error::IntegerOverflow),
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::Index, ValueType::Integer],
+        )),
+    }
+}
+
+pub fn pop_float(interp: &mut Interpreter) -> Result<f64, Error> {
+    match pop(interp)? {
+        Value::Float(v) => Ok(v),
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::Float],
+        )),
+    }
+}
+
+pub fn pop_string(interp: &mut Interpreter) -> Result<String, Error> {
+    match pop(interp)? {
+        Value::String(v) => Ok(v),
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::String],
+        )),
+    }
+}
+
+pub fn pop_symbol(interp: &mut Interpreter) -> Result<String, Error> {
+    match pop(interp)? {
+        Value::Symbol(v) => Ok(v),
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::Symbol],
+        )),
+    }
+}
+
+pub fn pop_array(interp: &mut Interpreter) -> Result<Vec<Value>, Error> {
+    match pop(interp)? {
+        Value::Array(v) => Ok(v),
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::Array],
+        )),
+    }
+}
+
+pub fn pop_map(interp: &mut Interpreter) -> Result<ValueMap, Error> {
+    match pop(interp)? {
+        Value::Map(v) => Ok(v),
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::Map],
+        )),
+    }
+}
+
+/// Pops an error value as (kind, message)
+pub fn pop_error(interp: &mut Interpreter) -> Result<(String, String), Error> {
+    match pop(interp)? {
+        Value::Error(kind, message) => Ok((kind, message)),
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::Error],
+        )),
+    }
+}
@@ -0,0 +1,145 @@
+//! Unsigned arithmetic that promotes to big integers on overflow,
+//! and demotes the result back to `Integer` whenever it fits
+
+use std::convert::TryFrom;
+
+use num_bigint::BigUint;
+use num_traits::{pow, ToPrimitive, Zero};
+
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::value::{BuiltinFunction, Value, ValueType};
+
+use super::args::pop;
+
+/// Largest result `pow` computes, in bits, so a script cannot exhaust memory
+const MAX_POW_BITS: u64 = 1 << 24;
+
+fn pop_operand(interp: &mut Interpreter) -> Result<Value, Error> {
+    let arg = pop(interp)?;
+    match arg {
+        Value::Integer(_) | Value::BigInteger(_) => Ok(arg),
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::Integer, ValueType::BigInteger],
+        )),
+    }
+}
+
+fn to_big(value: Value) -> BigUint {
+    match value {
+        Value::Integer(v) => BigUint::from(v),
+        Value::BigInteger(v) => v,
+        _ => unreachable!("Operands are type checked when popped"),
+    }
+}
+
+pub fn normalize(value: BigUint) -> Value {
+    match value.to_u64() {
+        Some(v) => Value::Integer(v),
+        None => Value::BigInteger(value),
+    }
+}
+
+/// Uses `small` if both operands are `Integer`s and it does not overflow,
+/// otherwise falls back to `big`
+fn binary_op(
+    interp: &mut Interpreter,
+    small: fn(u64, u64) -> Option<u64>,
+    big: fn(BigUint, BigUint) -> Result<BigUint, Error>,
+) -> Result<(), Error> {
+    let arg1 = pop_operand(interp)?;
+    let arg0 = pop_operand(interp)?;
+
+    if let (Value::Integer(a0), Value::Integer(a1)) = (&arg0, &arg1) {
+        if let Some(r) = small(*a0, *a1) {
+            interp.data.push(Value::Integer(r));
+            return Ok(());
+        }
+    }
+
+    let r = big(to_big(arg0), to_big(arg1))?;
+    interp.data.push(normalize(r));
+    Ok(())
+}
+
+/// (a b -- a+b)
+fn f_add(interp: &mut Interpreter) -> Result<(), Error> {
+    binary_op(interp, u64::checked_add, |a, b| Ok(a + b))
+}
+
+/// (a b -- a-b)
+fn f_sub(interp: &mut Interpreter) -> Result<(), Error> {
+    binary_op(interp, u64::checked_sub, |a, b| {
+        if a < b {
+            Err(Error::IntegerOverflow)
+        } else {
+            Ok(a - b)
+        }
+    })
+}
+
+/// (a b -- a*b)
+fn f_mul(interp: &mut Interpreter) -> Result<(), Error> {
+    binary_op(interp, u64::checked_mul, |a, b| Ok(a * b))
+}
+
+/// (a b -- a/b)
+fn f_div(interp: &mut Interpreter) -> Result<(), Error> {
+    binary_op(interp, u64::checked_div, |a, b| {
+        if b.is_zero() {
+            Err(Error::DivisionByZero)
+        } else {
+            Ok(a / b)
+        }
+    })
+}
+
+/// (a b -- a%b)
+fn f_rem(interp: &mut Interpreter) -> Result<(), Error> {
+    binary_op(interp, u64::checked_rem, |a, b| {
+        if b.is_zero() {
+            Err(Error::DivisionByZero)
+        } else {
+            Ok(a % b)
+        }
+    })
+}
+
+/// (a exp -- a^exp)
+fn f_pow(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = pop(interp)?;
+    let exp = if let Value::Integer(a1) = arg1 {
+        u32::try_from(a1).map_err(|_| Error::IntegerOverflow)?
+    } else {
+        return Err(Error::WrongArgumentType(
+            arg1.type_(),
+            vec![ValueType::Integer],
+        ));
+    };
+    let arg0 = pop_operand(interp)?;
+
+    if let Value::Integer(a0) = arg0 {
+        if let Some(r) = a0.checked_pow(exp) {
+            interp.data.push(Value::Integer(r));
+            return Ok(());
+        }
+    }
+
+    let base = to_big(arg0);
+    if base.bits() as u64 * u64::from(exp) > MAX_POW_BITS {
+        return Err(Error::IntegerOverflow);
+    }
+    let r = pow(base, exp as usize);
+    interp.data.push(normalize(r));
+    Ok(())
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new("add", f_add));
+    interp.register_builtin(BuiltinFunction::new("sub", f_sub));
+    interp.register_builtin(BuiltinFunction::new("mul", f_mul));
+    interp.register_builtin(BuiltinFunction::new("div", f_div));
+    interp.register_builtin(BuiltinFunction::new("rem", f_rem));
+    interp.register_builtin(BuiltinFunction::new("pow", f_pow));
+}
@@ -0,0 +1,106 @@
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::value::{BuiltinFunction, Value};
+
+use super::args::{pop, pop_array, pop_index};
+
+/// (arr v -- arr')
+fn f_push(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop(interp)?;
+    let mut a0 = pop_array(interp)?;
+    a0.push(a1);
+    interp.data.push(Value::Array(a0));
+    Ok(())
+}
+
+/// (arr -- arr' v)
+fn f_pop(interp: &mut Interpreter) -> Result<(), Error> {
+    let mut a0 = pop_array(interp)?;
+    let last = a0.pop().ok_or(Error::IndexOutOfBounds(0, 0))?;
+    interp.data.push(Value::Array(a0));
+    interp.data.push(last);
+    Ok(())
+}
+
+/// (arr index -- v)
+fn f_get(interp: &mut Interpreter) -> Result<(), Error> {
+    let index = pop_index(interp)?;
+    let a0 = pop_array(interp)?;
+    let length = a0.len();
+    let value = a0
+        .into_iter()
+        .nth(index)
+        .ok_or(Error::IndexOutOfBounds(index, length))?;
+    interp.data.push(value);
+    Ok(())
+}
+
+/// (arr index v -- arr')
+fn f_set(interp: &mut Interpreter) -> Result<(), Error> {
+    let a2 = pop(interp)?;
+    let index = pop_index(interp)?;
+    let mut a0 = pop_array(interp)?;
+    let length = a0.len();
+    match a0.get_mut(index) {
+        Some(item) => *item = a2,
+        None => return Err(Error::IndexOutOfBounds(index, length)),
+    };
+    interp.data.push(Value::Array(a0));
+    Ok(())
+}
+
+/// (arr -- n)
+fn f_length(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_array(interp)?;
+    interp.data.push(Value::Integer(a0.len() as u64));
+    Ok(())
+}
+
+/// (arr start end -- arr') Range `start..end`
+fn f_slice(interp: &mut Interpreter) -> Result<(), Error> {
+    let end = pop_index(interp)?;
+    let start = pop_index(interp)?;
+    let a0 = pop_array(interp)?;
+
+    if end > a0.len() {
+        return Err(Error::IndexOutOfBounds(end, a0.len()));
+    }
+    if start > end {
+        return Err(Error::InvalidRange(start, end));
+    }
+
+    interp.data.push(Value::Array(a0[start..end].to_vec()));
+    Ok(())
+}
+
+/// (a b -- ab)
+fn f_concat(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop_array(interp)?;
+    let mut a0 = pop_array(interp)?;
+    a0.extend(a1);
+    interp.data.push(Value::Array(a0));
+    Ok(())
+}
+
+/// (arr v -- index true | false) Index of the first equal item
+fn f_index_of(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop(interp)?;
+    let a0 = pop_array(interp)?;
+    let r = a0.iter().position(|item| item.numeric_eq(&a1));
+    if let Some(index) = r {
+        interp.data.push(Value::Index(index));
+    }
+    interp.data.push(Value::Boolean(r.is_some()));
+    Ok(())
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new("array::push", f_push));
+    interp.register_builtin(BuiltinFunction::new("array::pop", f_pop));
+    interp.register_builtin(BuiltinFunction::new("array::get", f_get));
+    interp.register_builtin(BuiltinFunction::new("array::set", f_set));
+    interp.register_builtin(BuiltinFunction::new("array::length", f_length));
+    interp.register_builtin(BuiltinFunction::new("array::slice", f_slice));
+    interp.register_builtin(BuiltinFunction::new("array::concat", f_concat));
+    interp.register_builtin(BuiltinFunction::new("array::index_of", f_index_of));
+}
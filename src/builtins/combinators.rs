@@ -0,0 +1,186 @@
+//! Higher-order combinators on quotations
+
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::namespace::AbsoluteSymbolPath;
+use crate::scanner::{Spanned, Token};
+use crate::value::{BuiltinFunction, UserFunction, Value};
+
+use super::args::{pop, pop_array, pop_usize, require};
+use super::control_flow::with_pinned;
+
+/// Tokens that have the same effect as executing `quot`, wherever they are.
+/// Function bodies cannot be inlined, as they resolve names in their own namespace
+fn quotation_tokens(quot: Value) -> Vec<Spanned> {
+    vec![
+        Token::Literal(quot).into(),
+        Token::Identifier("::exec".to_owned()).into(),
+    ]
+}
+
+/// Function that only uses absolute names
+fn anonymous_function(body: Vec<Spanned>) -> UserFunction {
+    UserFunction::new(AbsoluteSymbolPath::root(), body)
+}
+
+/// Calls each quotation with its value pushed first
+fn apply_pairs(
+    interp: &mut Interpreter,
+    pairs: Vec<(Value, Value)>,
+) -> Result<(), Error> {
+    let count = pairs.len();
+    let flat = pairs.into_iter().flat_map(|(v, q)| vec![v, q]).collect();
+    with_pinned(interp, flat, |interp, base| {
+        for i in 0..count {
+            interp.data.push(interp.pinned[base + 2 * i].clone());
+            let quot = interp.pinned[base + 2 * i + 1].clone();
+            interp.call_value(quot)?;
+        }
+        Ok(())
+    })
+}
+
+/// (x quot -- x) Calls `quot` with `x` temporarily removed
+fn f_dip(interp: &mut Interpreter) -> Result<(), Error> {
+    require(interp, 2)?;
+    let quot = pop(interp)?;
+    let x = pop(interp)?;
+    with_pinned(interp, vec![x], |interp, base| {
+        interp.call_value(quot)?;
+        interp.data.push(interp.pinned[base].clone());
+        Ok(())
+    })
+}
+
+/// (x quot -- x) Calls `quot` with `x`, then pushes `x` again
+fn f_keep(interp: &mut Interpreter) -> Result<(), Error> {
+    require(interp, 2)?;
+    let quot = pop(interp)?;
+    let x = pop(interp)?;
+    interp.data.push(x.clone());
+    with_pinned(interp, vec![x], |interp, base| {
+        interp.call_value(quot)?;
+        interp.data.push(interp.pinned[base].clone());
+        Ok(())
+    })
+}
+
+/// (x p q --) Calls both `p` and `q` with `x`
+fn f_bi(interp: &mut Interpreter) -> Result<(), Error> {
+    require(interp, 3)?;
+    let q = pop(interp)?;
+    let p = pop(interp)?;
+    let x = pop(interp)?;
+    apply_pairs(interp, vec![(x.clone(), p), (x, q)])
+}
+
+/// (x p q r --) Calls each of `p`, `q` and `r` with `x`
+fn f_tri(interp: &mut Interpreter) -> Result<(), Error> {
+    require(interp, 4)?;
+    let r = pop(interp)?;
+    let q = pop(interp)?;
+    let p = pop(interp)?;
+    let x = pop(interp)?;
+    apply_pairs(interp, vec![(x.clone(), p), (x.clone(), q), (x, r)])
+}
+
+/// (x [quots] --) Calls every quotation with `x`
+fn f_cleave(interp: &mut Interpreter) -> Result<(), Error> {
+    require(interp, 2)?;
+    let quots = pop_array(interp)?;
+    let x = pop(interp)?;
+    let pairs = quots.into_iter().map(|q| (x.clone(), q)).collect();
+    apply_pairs(interp, pairs)
+}
+
+/// (x1 .. xn [quots] --) Calls the i-th quotation with `xi`
+fn f_spread(interp: &mut Interpreter) -> Result<(), Error> {
+    let n = match interp.data.last() {
+        Some(Value::Array(quots)) => quots.len(),
+        _ => 0,
+    };
+    require(interp, n + 1)?;
+    let quots = pop_array(interp)?;
+    let start = interp.data.len() - quots.len();
+    let values = interp.data.split_off(start);
+    apply_pairs(interp, values.into_iter().zip(quots).collect())
+}
+
+/// Pops `x1 .. xn quot n`, returning the quotation and the `n` values
+fn pop_quotation_and_values(
+    interp: &mut Interpreter,
+) -> Result<(Value, Vec<Value>), Error> {
+    require(interp, 2)?;
+    let n = pop_usize(interp)?;
+    let available = interp.data.len() - 1;
+    if n > available {
+        return Err(Error::NotEnoughArguments(
+            n.saturating_add(2),
+            available + 2,
+        ));
+    }
+    let quot = pop(interp)?;
+    let start = interp.data.len() - n;
+    let values = interp.data.split_off(start);
+    Ok((quot, values))
+}
+
+/// (x1 .. xn quot n --) Calls `quot` with each of the `n` values
+fn f_napply(interp: &mut Interpreter) -> Result<(), Error> {
+    let (quot, values) = pop_quotation_and_values(interp)?;
+    let pairs = values.into_iter().map(|v| (v, quot.clone())).collect();
+    apply_pairs(interp, pairs)
+}
+
+/// (p q -- pq) Function that calls `p` and then `q`
+fn f_compose(interp: &mut Interpreter) -> Result<(), Error> {
+    require(interp, 2)?;
+    let q = pop(interp)?;
+    let p = pop(interp)?;
+    let mut tokens = quotation_tokens(p);
+    tokens.extend(quotation_tokens(q));
+    let f = anonymous_function(tokens);
+    interp.data.push(Value::Function(f));
+    Ok(())
+}
+
+/// (x quot -- quot') Function that pushes `x` and then calls `quot`
+fn f_curry(interp: &mut Interpreter) -> Result<(), Error> {
+    require(interp, 2)?;
+    let quot = pop(interp)?;
+    let x = pop(interp)?;
+    let mut tokens = vec![Token::Literal(x).into()];
+    tokens.extend(quotation_tokens(quot));
+    let f = anonymous_function(tokens);
+    interp.data.push(Value::Function(f));
+    Ok(())
+}
+
+/// (x1 .. xn quot n -- closure) Function that pushes the captured values
+/// and then calls `quot`
+fn f_closure(interp: &mut Interpreter) -> Result<(), Error> {
+    let (quot, mut values) = pop_quotation_and_values(interp)?;
+
+    let mut f = match quot {
+        Value::Function(f) => f,
+        other => anonymous_function(quotation_tokens(other)),
+    };
+    // Values the quotation captured itself are pushed after the new ones
+    values.extend(f.captured);
+    f.captured = values;
+    interp.data.push(Value::Function(f));
+    Ok(())
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new("dip", f_dip));
+    interp.register_builtin(BuiltinFunction::new("keep", f_keep));
+    interp.register_builtin(BuiltinFunction::new("bi", f_bi));
+    interp.register_builtin(BuiltinFunction::new("tri", f_tri));
+    interp.register_builtin(BuiltinFunction::new("cleave", f_cleave));
+    interp.register_builtin(BuiltinFunction::new("spread", f_spread));
+    interp.register_builtin(BuiltinFunction::new("napply", f_napply));
+    interp.register_builtin(BuiltinFunction::new("compose", f_compose));
+    interp.register_builtin(BuiltinFunction::new("curry", f_curry));
+    interp.register_builtin(BuiltinFunction::new("closure", f_closure));
+}
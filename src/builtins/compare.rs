@@ -6,28 +6,57 @@ fn f_eq(interp: &mut Interpreter) -> Result<(), Error> {
     let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
     let arg2 = interp.data.pop().ok_or(Error::StackUndeflow)?;
 
-    interp.data.push(Value::Boolean(arg1 == arg2));
+    interp.data.push(Value::Boolean(arg2.numeric_eq(&arg1)));
     Ok(())
 }
 
 fn f_lt(interp: &mut Interpreter) -> Result<(), Error> {
     let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
     let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
-    if let Value::Integer(a0) = arg0 {
-        if let Value::Integer(a1) = arg1 {
+    match (&arg0, &arg1) {
+        (Value::Integer(a0), Value::Integer(a1)) => {
             interp.data.push(Value::Boolean(a0 < a1));
             Ok(())
-        } else {
+        },
+        (Value::BigInteger(a0), Value::BigInteger(a1)) => {
+            interp.data.push(Value::Boolean(a0 < a1));
+            Ok(())
+        },
+        // Big integers are always larger than any `Integer`
+        (Value::Integer(_), Value::BigInteger(_)) => {
+            interp.data.push(Value::Boolean(true));
+            Ok(())
+        },
+        (Value::BigInteger(_), Value::Integer(_)) => {
+            interp.data.push(Value::Boolean(false));
+            Ok(())
+        },
+        (Value::SignedInteger(a0), Value::SignedInteger(a1)) => {
+            interp.data.push(Value::Boolean(a0 < a1));
+            Ok(())
+        },
+        (Value::Float(a0), Value::Float(a1)) => {
+            interp.data.push(Value::Boolean(a0 < a1));
+            Ok(())
+        },
+        (Value::Integer(_), _) | (Value::BigInteger(_), _) => {
             Err(Error::WrongArgumentType(
                 arg1.type_(),
-                vec![ValueType::Integer],
+                vec![ValueType::Integer, ValueType::BigInteger],
             ))
-        }
-    } else {
-        Err(Error::WrongArgumentType(
+        },
+        (Value::SignedInteger(_), _) | (Value::Float(_), _) => {
+            Err(Error::WrongArgumentType(arg1.type_(), vec![arg0.type_()]))
+        },
+        _ => Err(Error::WrongArgumentType(
             arg0.type_(),
-            vec![ValueType::Integer],
-        ))
+            vec![
+                ValueType::Integer,
+                ValueType::BigInteger,
+                ValueType::SignedInteger,
+                ValueType::Float,
+            ],
+        )),
     }
 }
 
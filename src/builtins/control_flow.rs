@@ -2,6 +2,8 @@ use crate::error::Error;
 use crate::interpreter::Interpreter;
 use crate::value::{BuiltinFunction, Value, ValueType};
 
+use super::args::{pop, pop_array, pop_boolean, pop_integer};
+
 /// Pop (unnamed) function from stack and excutes that (i.e. calls or pushes)
 fn f_exec(interp: &mut Interpreter) -> Result<(), Error> {
     let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
@@ -25,7 +27,294 @@ fn f_if(interp: &mut Interpreter) -> Result<(), Error> {
     }
 }
 
+/// Leaves the current function early, also from inside loop bodies
+fn f_return(interp: &mut Interpreter) -> Result<(), Error> {
+    interp.return_from_function()
+}
+
+/// (bool then else --)
+fn f_ifte(interp: &mut Interpreter) -> Result<(), Error> {
+    let a2 = pop(interp)?;
+    let a1 = pop(interp)?;
+    let a0 = pop_boolean(interp)?;
+    interp.execute_value(if a0 { a1 } else { a2 })
+}
+
+/// (bool body --)
+fn f_when(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop(interp)?;
+    let a0 = pop_boolean(interp)?;
+    if a0 {
+        interp.execute_value(a1)?;
+    }
+    Ok(())
+}
+
+/// (bool body --)
+fn f_unless(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop(interp)?;
+    let a0 = pop_boolean(interp)?;
+    if !a0 {
+        interp.execute_value(a1)?;
+    }
+    Ok(())
+}
+
+/// (branches --) Runs the body of the first branch whose predicate
+/// leaves true, e.g. `[ [ { dup 0 eq } { "zero" } ] [ { "other" } ] ] cond`
+fn f_cond(interp: &mut Interpreter) -> Result<(), Error> {
+    let branches = pop_array(interp)?;
+    let mut body = None;
+    with_pinned(interp, branches.clone(), |interp, _| {
+        for branch in branches {
+            let mut parts = match branch {
+                Value::Array(parts) => parts,
+                other => {
+                    return Err(Error::WrongArgumentType(
+                        other.type_(),
+                        vec![ValueType::Array],
+                    ))
+                },
+            };
+            match parts.len() {
+                1 => {
+                    body = parts.pop();
+                    return Ok(());
+                },
+                2 => {
+                    let pred = parts.remove(0);
+                    interp.call_value(pred)?;
+                    if pop_boolean(interp)? {
+                        body = parts.pop();
+                        return Ok(());
+                    }
+                },
+                n => return Err(Error::InvalidBranch(n)),
+            };
+        }
+        Ok(())
+    })?;
+    interp.execute_value(body.ok_or(Error::NoMatchingBranch)?)
+}
+
+/// (v branches -- v) Runs the body of the first branch whose pattern
+/// equals `v` or is the type of `v`, e.g. `[ [ 0 { "zero" } ] [ Integer
+/// { "other" } ] [ { "default" } ] ] case`. `v` is left for the body.
+fn f_case(interp: &mut Interpreter) -> Result<(), Error> {
+    let branches = pop_array(interp)?;
+    let value = interp.data.last().ok_or(Error::StackUndeflow)?.clone();
+
+    for branch in branches {
+        let mut parts = match branch {
+            Value::Array(parts) => parts,
+            other => {
+                return Err(Error::WrongArgumentType(
+                    other.type_(),
+                    vec![ValueType::Array],
+                ))
+            },
+        };
+        let matches = match parts.len() {
+            1 => true,
+            2 => {
+                let pattern = parts.remove(0);
+                match pattern {
+                    Value::Type(t) => pattern == value || t == value.type_(),
+                    pattern => pattern.numeric_eq(&value),
+                }
+            },
+            n => return Err(Error::InvalidBranch(n)),
+        };
+        if matches {
+            let body = parts.pop().expect("Length checked above");
+            return interp.execute_value(body);
+        }
+    }
+    Err(Error::NoMatchingCase(value))
+}
+
+/// Runs `f` with `values` pinned, unpinning them even if it fails
+pub(super) fn with_pinned<F>(
+    interp: &mut Interpreter,
+    values: Vec<Value>,
+    f: F,
+) -> Result<(), Error>
+where
+    F: FnOnce(&mut Interpreter, usize) -> Result<(), Error>,
+{
+    let base = interp.pinned.len();
+    interp.pinned.extend(values);
+    let r = f(interp, base);
+    interp.pinned.truncate(base);
+    r
+}
+
+/// (cond body --) Runs `body` as long as `cond` leaves true
+fn f_while(interp: &mut Interpreter) -> Result<(), Error> {
+    let body = pop(interp)?;
+    let cond = pop(interp)?;
+    with_pinned(interp, vec![cond, body], |interp, base| loop {
+        let cond = interp.pinned[base].clone();
+        interp.call_value(cond)?;
+        if !pop_boolean(interp)? {
+            return Ok(());
+        }
+        let body = interp.pinned[base + 1].clone();
+        interp.call_value(body)?;
+    })
+}
+
+/// (n body --)
+fn f_times(interp: &mut Interpreter) -> Result<(), Error> {
+    let body = pop(interp)?;
+    let count = pop_integer(interp)?;
+    with_pinned(interp, vec![body], |interp, base| {
+        for _ in 0..count {
+            let body = interp.pinned[base].clone();
+            interp.call_value(body)?;
+        }
+        Ok(())
+    })
+}
+
+/// (n body --) Pushes the `Index` of the iteration before each run
+fn f_times_indexed(interp: &mut Interpreter) -> Result<(), Error> {
+    let body = pop(interp)?;
+    let count = pop_integer(interp)?;
+    with_pinned(interp, vec![body], |interp, base| {
+        for i in 0..count {
+            interp.data.push(Value::Index(i as usize));
+            let body = interp.pinned[base].clone();
+            interp.call_value(body)?;
+        }
+        Ok(())
+    })
+}
+
+/// (collection body --) Runs `body` on each array item, on each key
+/// and value of a map, or on each character of a string
+fn f_each(interp: &mut Interpreter) -> Result<(), Error> {
+    let body = pop(interp)?;
+    let items: Vec<Vec<Value>> = match pop(interp)? {
+        Value::Array(v) => v.into_iter().map(|item| vec![item]).collect(),
+        Value::Map(m) => m.0.into_iter().map(|(k, v)| vec![k, v]).collect(),
+        Value::String(s) => s
+            .chars()
+            .map(|c| vec![Value::String(c.to_string())])
+            .collect(),
+        other => {
+            return Err(Error::WrongArgumentType(
+                other.type_(),
+                vec![ValueType::Array, ValueType::Map, ValueType::String],
+            ))
+        },
+    };
+
+    // Remaining items are kept pinned in reverse order
+    let mut pinned = vec![body];
+    pinned.extend(items.into_iter().rev().map(Value::Array));
+    with_pinned(interp, pinned, |interp, base| {
+        while interp.pinned.len() > base + 1 {
+            if let Some(Value::Array(item)) = interp.pinned.pop() {
+                interp.data.extend(item);
+            }
+            let body = interp.pinned[base].clone();
+            interp.call_value(body)?;
+        }
+        Ok(())
+    })
+}
+
+/// (body handler --) Runs `handler` if `body` fails, with the data stack
+/// as it was before `body` and the thrown value or an `Error` on top.
+/// Errors carry the kind of failure, see `error::kind`.
+fn f_try(interp: &mut Interpreter) -> Result<(), Error> {
+    let handler = pop(interp)?;
+    let body = pop(interp)?;
+    let cp = interp.checkpoint();
+
+    interp.pinned.push(Value::Array(interp.data.clone()));
+    interp.pinned.push(handler);
+    let r = interp.call_value(body);
+    let span = interp.current_span();
+    let handler = interp.pinned.pop().expect("Pinned above");
+    let saved = interp.pinned.pop().expect("Pinned above");
+
+    if let Err(Error::Return) = r {
+        return r;
+    }
+    if let Err(e) = r {
+        interp.recover(cp);
+        if let Value::Array(saved) = saved {
+            interp.data = saved;
+        }
+        interp.data.push(match e {
+            Error::Thrown(value) => value,
+            e => {
+                let message = match span {
+                    Some(span) => format!("{:?} at {}", e, span),
+                    None => format!("{:?}", e),
+                };
+                Value::Error(e.kind().to_owned(), message)
+            },
+        });
+        interp.execute_value(handler)?;
+    }
+    Ok(())
+}
+
+/// (v --)
+fn f_throw(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop(interp)?;
+    Err(Error::Thrown(a0))
+}
+
+/// (body cleanup --) Runs `cleanup` after `body`, even if `body` fails.
+/// After a failure `cleanup` sees the data stack as it was before `body`.
+fn f_finally(interp: &mut Interpreter) -> Result<(), Error> {
+    let cleanup = pop(interp)?;
+    let body = pop(interp)?;
+    let cp = interp.checkpoint();
+
+    interp.pinned.push(Value::Array(interp.data.clone()));
+    interp.pinned.push(cleanup);
+    let r = interp.call_value(body);
+    let cleanup = interp.pinned.pop().expect("Pinned above");
+    let saved = interp.pinned.pop().expect("Pinned above");
+
+    let failed = match r {
+        Ok(()) | Err(Error::Return) => false,
+        Err(_) => true,
+    };
+    if failed {
+        interp.recover(cp);
+        if let Value::Array(saved) = saved {
+            interp.data = saved;
+        }
+    }
+    // Until it is returned, the thrown value is only held by `r`
+    let thrown = match &r {
+        Err(Error::Thrown(v)) => vec![v.clone()],
+        _ => Vec::new(),
+    };
+    with_pinned(interp, thrown, |interp, _| interp.call_value(cleanup))?;
+    r
+}
+
 pub fn register_all(interp: &mut Interpreter) {
     interp.register_builtin(BuiltinFunction::new("exec", f_exec));
     interp.register_builtin(BuiltinFunction::new("if", f_if));
+    interp.register_builtin(BuiltinFunction::new("return", f_return));
+    interp.register_builtin(BuiltinFunction::new("ifte", f_ifte));
+    interp.register_builtin(BuiltinFunction::new("when", f_when));
+    interp.register_builtin(BuiltinFunction::new("unless", f_unless));
+    interp.register_builtin(BuiltinFunction::new("cond", f_cond));
+    interp.register_builtin(BuiltinFunction::new("case", f_case));
+    interp.register_builtin(BuiltinFunction::new("try", f_try));
+    interp.register_builtin(BuiltinFunction::new("throw", f_throw));
+    interp.register_builtin(BuiltinFunction::new("finally", f_finally));
+    interp.register_builtin(BuiltinFunction::new("while", f_while));
+    interp.register_builtin(BuiltinFunction::new("times", f_times));
+    interp.register_builtin(BuiltinFunction::new("times_indexed", f_times_indexed));
+    interp.register_builtin(BuiltinFunction::new("each", f_each));
 }
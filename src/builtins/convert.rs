@@ -0,0 +1,112 @@
+//! Explicit conversions between numeric types
+
+use std::convert::TryFrom;
+
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::value::{BuiltinFunction, Value, ValueType};
+
+use super::args::{pop, pop_float, pop_integer, pop_usize};
+
+fn pop_signed(interp: &mut Interpreter) -> Result<i64, Error> {
+    match pop(interp)? {
+        Value::SignedInteger(v) => Ok(v),
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::SignedInteger],
+        )),
+    }
+}
+
+/// (Integer -- SignedInteger)
+fn f_signed_from_integer(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_integer(interp)?;
+    let r = i64::try_from(a0).map_err(|_| Error::IntegerOverflow)?;
+    interp.data.push(Value::SignedInteger(r));
+    Ok(())
+}
+
+/// (SignedInteger -- Integer)
+fn f_signed_to_integer(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_signed(interp)?;
+    let r = u64::try_from(a0).map_err(|_| Error::IntegerOverflow)?;
+    interp.data.push(Value::Integer(r));
+    Ok(())
+}
+
+/// (Integer -- Float) Rounds to the nearest representable value
+fn f_float_from_integer(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_integer(interp)?;
+    interp.data.push(Value::Float(a0 as f64));
+    Ok(())
+}
+
+/// (SignedInteger -- Float) Rounds to the nearest representable value
+fn f_float_from_signed(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_signed(interp)?;
+    interp.data.push(Value::Float(a0 as f64));
+    Ok(())
+}
+
+/// (Float -- SignedInteger) Truncates towards zero
+fn f_float_to_signed(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_float(interp)?.trunc();
+    // Both bounds are exactly representable as f64
+    if a0.is_nan()
+        || a0 < -9_223_372_036_854_775_808.0
+        || a0 >= 9_223_372_036_854_775_808.0
+    {
+        return Err(Error::IntegerOverflow);
+    }
+    interp.data.push(Value::SignedInteger(a0 as i64));
+    Ok(())
+}
+
+/// (Integer -- Index)
+fn f_index_from_integer(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_usize(interp)?;
+    interp.data.push(Value::Index(a0));
+    Ok(())
+}
+
+/// (Index -- Integer)
+fn f_index_to_integer(interp: &mut Interpreter) -> Result<(), Error> {
+    match pop(interp)? {
+        Value::Index(v) => {
+            interp.data.push(Value::Integer(v as u64));
+            Ok(())
+        },
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::Index],
+        )),
+    }
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::from_integer",
+        f_signed_from_integer,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::to_integer",
+        f_signed_to_integer,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "float::from_integer",
+        f_float_from_integer,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "float::from_signed",
+        f_float_from_signed,
+    ));
+    interp.register_builtin(BuiltinFunction::new("float::to_signed", f_float_to_signed));
+    interp.register_builtin(BuiltinFunction::new(
+        "index::from_integer",
+        f_index_from_integer,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "index::to_integer",
+        f_index_to_integer,
+    ));
+}
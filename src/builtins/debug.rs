@@ -38,9 +38,20 @@ fn f_dbgshowstack(interp: &mut Interpreter) -> Result<(), Error> {
     Ok(())
 }
 
+fn f_dbgshowframes(interp: &mut Interpreter) -> Result<(), Error> {
+    for frame in interp.frames() {
+        match &frame.name {
+            Some(name) => println!("{}", name.0.join("::")),
+            None => println!("(anonymous in ::{})", frame.namespace.0.join("::")),
+        }
+    }
+    Ok(())
+}
+
 pub fn register_all(interp: &mut Interpreter) {
     interp.register_builtin(BuiltinFunction::new("assert", f_assert));
     interp.register_builtin(BuiltinFunction::new("dbgshow", f_dbgshow));
+    interp.register_builtin(BuiltinFunction::new("dbgshowframes", f_dbgshowframes));
     interp.register_builtin(BuiltinFunction::new("dbgshowstack", f_dbgshowstack));
     interp.register_builtin(BuiltinFunction::new("dbgstackdepth", f_dbgstackdepth));
 }
@@ -0,0 +1,26 @@
+//! Inspecting errors caught by `try`
+
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::value::{BuiltinFunction, Value};
+
+use super::args::pop_error;
+
+/// (err -- sym) Kind of failure, e.g. `\StackUndeflow`
+fn f_kind(interp: &mut Interpreter) -> Result<(), Error> {
+    let (kind, _) = pop_error(interp)?;
+    interp.data.push(Value::Symbol(kind));
+    Ok(())
+}
+
+/// (err -- str) Description of the failure and where it happened
+fn f_message(interp: &mut Interpreter) -> Result<(), Error> {
+    let (_, message) = pop_error(interp)?;
+    interp.data.push(Value::String(message));
+    Ok(())
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new("error::kind", f_kind));
+    interp.register_builtin(BuiltinFunction::new("error::message", f_message));
+}
@@ -0,0 +1,62 @@
+//! Float arithmetic, which has no inherent methods to generate from
+
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::value::{BuiltinFunction, Value};
+
+use super::args::pop_float;
+
+/// (a b -- a+b)
+fn f_add(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop_float(interp)?;
+    let a0 = pop_float(interp)?;
+    interp.data.push(Value::Float(a0 + a1));
+    Ok(())
+}
+
+/// (a b -- a-b)
+fn f_sub(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop_float(interp)?;
+    let a0 = pop_float(interp)?;
+    interp.data.push(Value::Float(a0 - a1));
+    Ok(())
+}
+
+/// (a b -- a*b)
+fn f_mul(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop_float(interp)?;
+    let a0 = pop_float(interp)?;
+    interp.data.push(Value::Float(a0 * a1));
+    Ok(())
+}
+
+/// (a b -- a/b)
+fn f_div(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop_float(interp)?;
+    let a0 = pop_float(interp)?;
+    interp.data.push(Value::Float(a0 / a1));
+    Ok(())
+}
+
+/// (a -- -a)
+fn f_neg(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_float(interp)?;
+    interp.data.push(Value::Float(-a0));
+    Ok(())
+}
+
+/// (a -- bool)
+fn f_is_nan(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_float(interp)?;
+    interp.data.push(Value::Boolean(a0.is_nan()));
+    Ok(())
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new("float::add", f_add));
+    interp.register_builtin(BuiltinFunction::new("float::sub", f_sub));
+    interp.register_builtin(BuiltinFunction::new("float::mul", f_mul));
+    interp.register_builtin(BuiltinFunction::new("float::div", f_div));
+    interp.register_builtin(BuiltinFunction::new("float::neg", f_neg));
+    interp.register_builtin(BuiltinFunction::new("float::is_nan", f_is_nan));
+}
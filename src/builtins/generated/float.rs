@@ -0,0 +1,749 @@
+// !!! DO NOT EDIT !!!
+// All manual changes will be lost!
+// Autogenerated by build.rs
+
+use std::convert::TryFrom;
+
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::value::{BuiltinFunction, Value, ValueType};
+
+/// `floor(f64) -> f64`
+fn f_floor(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::floor(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `ceil(f64) -> f64`
+fn f_ceil(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::ceil(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `round(f64) -> f64`
+fn f_round(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::round(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `trunc(f64) -> f64`
+fn f_trunc(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::trunc(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `fract(f64) -> f64`
+fn f_fract(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::fract(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `abs(f64) -> f64`
+fn f_abs(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::abs(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `signum(f64) -> f64`
+fn f_signum(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::signum(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `mul_add(f64, f64, f64) -> f64`
+fn f_mul_add(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg2 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        if let Value::Float(a2) = arg2 {
+            if let Value::Float(a1) = arg1 {
+                let a0_conv = a0;
+                let a1_conv = a1;
+                let a2_conv = a2;
+                let r: f64 = f64::mul_add(a0_conv, a1_conv, a2_conv);
+                interp.data.push(Value::Float(r));
+                Ok(())
+            } else {
+                Err(Error::WrongArgumentType(
+                    arg1.type_(),
+                    vec![ValueType::Float],
+                ))
+            }
+        } else {
+            Err(Error::WrongArgumentType(
+                arg2.type_(),
+                vec![ValueType::Float],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `div_euc(f64, f64) -> f64`
+fn f_div_euc(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        if let Value::Float(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: f64 = f64::div_euc(a0_conv, a1_conv);
+            interp.data.push(Value::Float(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Float],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `mod_euc(f64, f64) -> f64`
+fn f_mod_euc(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        if let Value::Float(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: f64 = f64::mod_euc(a0_conv, a1_conv);
+            interp.data.push(Value::Float(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Float],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `powi(f64, i32) -> f64`
+fn f_powi(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = i32::try_from(a1).map_err(|_| Error::IntegerOverflow)?;
+            let r: f64 = f64::powi(a0_conv, a1_conv);
+            interp.data.push(Value::Float(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `powf(f64, f64) -> f64`
+fn f_powf(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        if let Value::Float(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: f64 = f64::powf(a0_conv, a1_conv);
+            interp.data.push(Value::Float(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Float],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `sqrt(f64) -> f64`
+fn f_sqrt(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::sqrt(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `exp(f64) -> f64`
+fn f_exp(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::exp(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `exp2(f64) -> f64`
+fn f_exp2(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::exp2(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `ln(f64) -> f64`
+fn f_ln(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::ln(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `log(f64, f64) -> f64`
+fn f_log(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        if let Value::Float(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: f64 = f64::log(a0_conv, a1_conv);
+            interp.data.push(Value::Float(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Float],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `log2(f64) -> f64`
+fn f_log2(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::log2(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `log10(f64) -> f64`
+fn f_log10(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::log10(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `abs_sub(f64, f64) -> f64`
+fn f_abs_sub(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        if let Value::Float(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: f64 = f64::abs_sub(a0_conv, a1_conv);
+            interp.data.push(Value::Float(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Float],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `cbrt(f64) -> f64`
+fn f_cbrt(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::cbrt(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `hypot(f64, f64) -> f64`
+fn f_hypot(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        if let Value::Float(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: f64 = f64::hypot(a0_conv, a1_conv);
+            interp.data.push(Value::Float(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Float],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `sin(f64) -> f64`
+fn f_sin(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::sin(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `cos(f64) -> f64`
+fn f_cos(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::cos(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `tan(f64) -> f64`
+fn f_tan(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::tan(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `asin(f64) -> f64`
+fn f_asin(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::asin(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `acos(f64) -> f64`
+fn f_acos(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::acos(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `atan(f64) -> f64`
+fn f_atan(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::atan(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `atan2(f64, f64) -> f64`
+fn f_atan2(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        if let Value::Float(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: f64 = f64::atan2(a0_conv, a1_conv);
+            interp.data.push(Value::Float(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Float],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `sin_cos(f64) -> (f64, f64)`
+fn f_sin_cos(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: (f64, f64) = f64::sin_cos(a0_conv);
+        interp.data.push(Value::Float(r.0));
+        interp.data.push(Value::Float(r.1));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `exp_m1(f64) -> f64`
+fn f_exp_m1(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::exp_m1(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `ln_1p(f64) -> f64`
+fn f_ln_1p(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::ln_1p(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `sinh(f64) -> f64`
+fn f_sinh(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::sinh(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `cosh(f64) -> f64`
+fn f_cosh(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::cosh(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `tanh(f64) -> f64`
+fn f_tanh(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::tanh(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `asinh(f64) -> f64`
+fn f_asinh(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::asinh(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `acosh(f64) -> f64`
+fn f_acosh(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::acosh(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+/// `atanh(f64) -> f64`
+fn f_atanh(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::Float(a0) = arg0 {
+        let a0_conv = a0;
+        let r: f64 = f64::atanh(a0_conv);
+        interp.data.push(Value::Float(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::Float],
+        ))
+    }
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new("float::floor", f_floor));
+    interp.register_builtin(BuiltinFunction::new("float::ceil", f_ceil));
+    interp.register_builtin(BuiltinFunction::new("float::round", f_round));
+    interp.register_builtin(BuiltinFunction::new("float::trunc", f_trunc));
+    interp.register_builtin(BuiltinFunction::new("float::fract", f_fract));
+    interp.register_builtin(BuiltinFunction::new("float::abs", f_abs));
+    interp.register_builtin(BuiltinFunction::new("float::signum", f_signum));
+    interp.register_builtin(BuiltinFunction::new("float::mul_add", f_mul_add));
+    interp.register_builtin(BuiltinFunction::new("float::div_euc", f_div_euc));
+    interp.register_builtin(BuiltinFunction::new("float::mod_euc", f_mod_euc));
+    interp.register_builtin(BuiltinFunction::new("float::powi", f_powi));
+    interp.register_builtin(BuiltinFunction::new("float::powf", f_powf));
+    interp.register_builtin(BuiltinFunction::new("float::sqrt", f_sqrt));
+    interp.register_builtin(BuiltinFunction::new("float::exp", f_exp));
+    interp.register_builtin(BuiltinFunction::new("float::exp2", f_exp2));
+    interp.register_builtin(BuiltinFunction::new("float::ln", f_ln));
+    interp.register_builtin(BuiltinFunction::new("float::log", f_log));
+    interp.register_builtin(BuiltinFunction::new("float::log2", f_log2));
+    interp.register_builtin(BuiltinFunction::new("float::log10", f_log10));
+    interp.register_builtin(BuiltinFunction::new("float::abs_sub", f_abs_sub));
+    interp.register_builtin(BuiltinFunction::new("float::cbrt", f_cbrt));
+    interp.register_builtin(BuiltinFunction::new("float::hypot", f_hypot));
+    interp.register_builtin(BuiltinFunction::new("float::sin", f_sin));
+    interp.register_builtin(BuiltinFunction::new("float::cos", f_cos));
+    interp.register_builtin(BuiltinFunction::new("float::tan", f_tan));
+    interp.register_builtin(BuiltinFunction::new("float::asin", f_asin));
+    interp.register_builtin(BuiltinFunction::new("float::acos", f_acos));
+    interp.register_builtin(BuiltinFunction::new("float::atan", f_atan));
+    interp.register_builtin(BuiltinFunction::new("float::atan2", f_atan2));
+    interp.register_builtin(BuiltinFunction::new("float::sin_cos", f_sin_cos));
+    interp.register_builtin(BuiltinFunction::new("float::exp_m1", f_exp_m1));
+    interp.register_builtin(BuiltinFunction::new("float::ln_1p", f_ln_1p));
+    interp.register_builtin(BuiltinFunction::new("float::sinh", f_sinh));
+    interp.register_builtin(BuiltinFunction::new("float::cosh", f_cosh));
+    interp.register_builtin(BuiltinFunction::new("float::tanh", f_tanh));
+    interp.register_builtin(BuiltinFunction::new("float::asinh", f_asinh));
+    interp.register_builtin(BuiltinFunction::new("float::acosh", f_acosh));
+    interp.register_builtin(BuiltinFunction::new("float::atanh", f_atanh));
+}
@@ -1 +1,3 @@
+pub mod float;
 pub mod int;
+pub mod signed;
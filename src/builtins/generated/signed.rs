@@ -0,0 +1,1303 @@
+// !!! DO NOT EDIT !!!
+// All manual changes will be lost!
+// Autogenerated by build.rs
+
+use std::convert::TryFrom;
+
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::value::{BuiltinFunction, Value, ValueType};
+
+/// `count_ones(i64) -> u32`
+fn f_count_ones(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: u32 = i64::count_ones(a0_conv);
+        interp.data.push(Value::Integer(u64::from(r)));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `count_zeros(i64) -> u32`
+fn f_count_zeros(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: u32 = i64::count_zeros(a0_conv);
+        interp.data.push(Value::Integer(u64::from(r)));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `leading_zeros(i64) -> u32`
+fn f_leading_zeros(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: u32 = i64::leading_zeros(a0_conv);
+        interp.data.push(Value::Integer(u64::from(r)));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `trailing_zeros(i64) -> u32`
+fn f_trailing_zeros(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: u32 = i64::trailing_zeros(a0_conv);
+        interp.data.push(Value::Integer(u64::from(r)));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `rotate_left(i64, u32) -> i64`
+fn f_rotate_left(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::Integer(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = u32::try_from(a1).map_err(|_| Error::IntegerOverflow)?;
+            let r: i64 = i64::rotate_left(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Integer],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `rotate_right(i64, u32) -> i64`
+fn f_rotate_right(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::Integer(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = u32::try_from(a1).map_err(|_| Error::IntegerOverflow)?;
+            let r: i64 = i64::rotate_right(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Integer],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `swap_bytes(i64) -> i64`
+fn f_swap_bytes(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: i64 = i64::swap_bytes(a0_conv);
+        interp.data.push(Value::SignedInteger(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `reverse_bits(i64) -> i64`
+fn f_reverse_bits(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: i64 = i64::reverse_bits(a0_conv);
+        interp.data.push(Value::SignedInteger(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `checked_add(i64, i64) -> Option<i64>`
+fn f_checked_add(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: Option<i64> = i64::checked_add(a0_conv, a1_conv);
+            if let Some(v) = r {
+                interp.data.push(Value::SignedInteger(v));
+            }
+            interp.data.push(Value::Boolean(r.is_some()));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `checked_sub(i64, i64) -> Option<i64>`
+fn f_checked_sub(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: Option<i64> = i64::checked_sub(a0_conv, a1_conv);
+            if let Some(v) = r {
+                interp.data.push(Value::SignedInteger(v));
+            }
+            interp.data.push(Value::Boolean(r.is_some()));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `checked_mul(i64, i64) -> Option<i64>`
+fn f_checked_mul(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: Option<i64> = i64::checked_mul(a0_conv, a1_conv);
+            if let Some(v) = r {
+                interp.data.push(Value::SignedInteger(v));
+            }
+            interp.data.push(Value::Boolean(r.is_some()));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `checked_div(i64, i64) -> Option<i64>`
+fn f_checked_div(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: Option<i64> = i64::checked_div(a0_conv, a1_conv);
+            if let Some(v) = r {
+                interp.data.push(Value::SignedInteger(v));
+            }
+            interp.data.push(Value::Boolean(r.is_some()));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `checked_div_euc(i64, i64) -> Option<i64>`
+fn f_checked_div_euc(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: Option<i64> = i64::checked_div_euc(a0_conv, a1_conv);
+            if let Some(v) = r {
+                interp.data.push(Value::SignedInteger(v));
+            }
+            interp.data.push(Value::Boolean(r.is_some()));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `checked_rem(i64, i64) -> Option<i64>`
+fn f_checked_rem(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: Option<i64> = i64::checked_rem(a0_conv, a1_conv);
+            if let Some(v) = r {
+                interp.data.push(Value::SignedInteger(v));
+            }
+            interp.data.push(Value::Boolean(r.is_some()));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `checked_mod_euc(i64, i64) -> Option<i64>`
+fn f_checked_mod_euc(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: Option<i64> = i64::checked_mod_euc(a0_conv, a1_conv);
+            if let Some(v) = r {
+                interp.data.push(Value::SignedInteger(v));
+            }
+            interp.data.push(Value::Boolean(r.is_some()));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `checked_neg(i64) -> Option<i64>`
+fn f_checked_neg(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: Option<i64> = i64::checked_neg(a0_conv);
+        if let Some(v) = r {
+            interp.data.push(Value::SignedInteger(v));
+        }
+        interp.data.push(Value::Boolean(r.is_some()));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `checked_shl(i64, u32) -> Option<i64>`
+fn f_checked_shl(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::Integer(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = u32::try_from(a1).map_err(|_| Error::IntegerOverflow)?;
+            let r: Option<i64> = i64::checked_shl(a0_conv, a1_conv);
+            if let Some(v) = r {
+                interp.data.push(Value::SignedInteger(v));
+            }
+            interp.data.push(Value::Boolean(r.is_some()));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Integer],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `checked_shr(i64, u32) -> Option<i64>`
+fn f_checked_shr(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::Integer(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = u32::try_from(a1).map_err(|_| Error::IntegerOverflow)?;
+            let r: Option<i64> = i64::checked_shr(a0_conv, a1_conv);
+            if let Some(v) = r {
+                interp.data.push(Value::SignedInteger(v));
+            }
+            interp.data.push(Value::Boolean(r.is_some()));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Integer],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `checked_abs(i64) -> Option<i64>`
+fn f_checked_abs(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: Option<i64> = i64::checked_abs(a0_conv);
+        if let Some(v) = r {
+            interp.data.push(Value::SignedInteger(v));
+        }
+        interp.data.push(Value::Boolean(r.is_some()));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `saturating_add(i64, i64) -> i64`
+fn f_saturating_add(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: i64 = i64::saturating_add(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `saturating_sub(i64, i64) -> i64`
+fn f_saturating_sub(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: i64 = i64::saturating_sub(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `saturating_mul(i64, i64) -> i64`
+fn f_saturating_mul(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: i64 = i64::saturating_mul(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `saturating_pow(i64, u32) -> i64`
+fn f_saturating_pow(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::Integer(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = u32::try_from(a1).map_err(|_| Error::IntegerOverflow)?;
+            let r: i64 = i64::saturating_pow(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Integer],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `wrapping_add(i64, i64) -> i64`
+fn f_wrapping_add(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: i64 = i64::wrapping_add(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `wrapping_sub(i64, i64) -> i64`
+fn f_wrapping_sub(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: i64 = i64::wrapping_sub(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `wrapping_mul(i64, i64) -> i64`
+fn f_wrapping_mul(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: i64 = i64::wrapping_mul(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `wrapping_div(i64, i64) -> i64`
+fn f_wrapping_div(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: i64 = i64::wrapping_div(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `wrapping_div_euc(i64, i64) -> i64`
+fn f_wrapping_div_euc(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: i64 = i64::wrapping_div_euc(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `wrapping_rem(i64, i64) -> i64`
+fn f_wrapping_rem(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: i64 = i64::wrapping_rem(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `wrapping_mod_euc(i64, i64) -> i64`
+fn f_wrapping_mod_euc(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: i64 = i64::wrapping_mod_euc(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `wrapping_neg(i64) -> i64`
+fn f_wrapping_neg(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: i64 = i64::wrapping_neg(a0_conv);
+        interp.data.push(Value::SignedInteger(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `wrapping_shl(i64, u32) -> i64`
+fn f_wrapping_shl(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::Integer(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = u32::try_from(a1).map_err(|_| Error::IntegerOverflow)?;
+            let r: i64 = i64::wrapping_shl(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Integer],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `wrapping_shr(i64, u32) -> i64`
+fn f_wrapping_shr(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::Integer(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = u32::try_from(a1).map_err(|_| Error::IntegerOverflow)?;
+            let r: i64 = i64::wrapping_shr(a0_conv, a1_conv);
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Integer],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `wrapping_abs(i64) -> i64`
+fn f_wrapping_abs(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: i64 = i64::wrapping_abs(a0_conv);
+        interp.data.push(Value::SignedInteger(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `overflowing_add(i64, i64) -> (i64, bool)`
+fn f_overflowing_add(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: (i64, bool) = i64::overflowing_add(a0_conv, a1_conv);
+            interp.data.push(Value::Boolean(r.1));
+            interp.data.push(Value::SignedInteger(r.0));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `overflowing_sub(i64, i64) -> (i64, bool)`
+fn f_overflowing_sub(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: (i64, bool) = i64::overflowing_sub(a0_conv, a1_conv);
+            interp.data.push(Value::Boolean(r.1));
+            interp.data.push(Value::SignedInteger(r.0));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `overflowing_mul(i64, i64) -> (i64, bool)`
+fn f_overflowing_mul(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: (i64, bool) = i64::overflowing_mul(a0_conv, a1_conv);
+            interp.data.push(Value::Boolean(r.1));
+            interp.data.push(Value::SignedInteger(r.0));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `overflowing_div(i64, i64) -> (i64, bool)`
+fn f_overflowing_div(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: (i64, bool) = i64::overflowing_div(a0_conv, a1_conv);
+            interp.data.push(Value::Boolean(r.1));
+            interp.data.push(Value::SignedInteger(r.0));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `overflowing_div_euc(i64, i64) -> (i64, bool)`
+fn f_overflowing_div_euc(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: (i64, bool) = i64::overflowing_div_euc(a0_conv, a1_conv);
+            interp.data.push(Value::Boolean(r.1));
+            interp.data.push(Value::SignedInteger(r.0));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `overflowing_rem(i64, i64) -> (i64, bool)`
+fn f_overflowing_rem(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: (i64, bool) = i64::overflowing_rem(a0_conv, a1_conv);
+            interp.data.push(Value::Boolean(r.1));
+            interp.data.push(Value::SignedInteger(r.0));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `overflowing_mod_euc(i64, i64) -> (i64, bool)`
+fn f_overflowing_mod_euc(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: (i64, bool) = i64::overflowing_mod_euc(a0_conv, a1_conv);
+            interp.data.push(Value::Boolean(r.1));
+            interp.data.push(Value::SignedInteger(r.0));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `overflowing_neg(i64) -> (i64, bool)`
+fn f_overflowing_neg(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: (i64, bool) = i64::overflowing_neg(a0_conv);
+        interp.data.push(Value::Boolean(r.1));
+        interp.data.push(Value::SignedInteger(r.0));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `overflowing_shl(i64, u32) -> (i64, bool)`
+fn f_overflowing_shl(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::Integer(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = u32::try_from(a1).map_err(|_| Error::IntegerOverflow)?;
+            let r: (i64, bool) = i64::overflowing_shl(a0_conv, a1_conv);
+            interp.data.push(Value::Boolean(r.1));
+            interp.data.push(Value::SignedInteger(r.0));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Integer],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `overflowing_shr(i64, u32) -> (i64, bool)`
+fn f_overflowing_shr(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::Integer(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = u32::try_from(a1).map_err(|_| Error::IntegerOverflow)?;
+            let r: (i64, bool) = i64::overflowing_shr(a0_conv, a1_conv);
+            interp.data.push(Value::Boolean(r.1));
+            interp.data.push(Value::SignedInteger(r.0));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::Integer],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `overflowing_abs(i64) -> (i64, bool)`
+fn f_overflowing_abs(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: (i64, bool) = i64::overflowing_abs(a0_conv);
+        interp.data.push(Value::Boolean(r.1));
+        interp.data.push(Value::SignedInteger(r.0));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `div_euc(i64, i64) -> i64`
+fn f_div_euc(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: i64 =
+                i64::checked_div_euc(a0_conv, a1_conv).ok_or(Error::IntegerOverflow)?;
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `mod_euc(i64, i64) -> i64`
+fn f_mod_euc(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg1 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        if let Value::SignedInteger(a1) = arg1 {
+            let a0_conv = a0;
+            let a1_conv = a1;
+            let r: i64 =
+                i64::checked_mod_euc(a0_conv, a1_conv).ok_or(Error::IntegerOverflow)?;
+            interp.data.push(Value::SignedInteger(r));
+            Ok(())
+        } else {
+            Err(Error::WrongArgumentType(
+                arg1.type_(),
+                vec![ValueType::SignedInteger],
+            ))
+        }
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `abs(i64) -> i64`
+fn f_abs(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: i64 = i64::checked_abs(a0_conv).ok_or(Error::IntegerOverflow)?;
+        interp.data.push(Value::SignedInteger(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `signum(i64) -> i64`
+fn f_signum(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: i64 = i64::signum(a0_conv);
+        interp.data.push(Value::SignedInteger(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `is_positive(i64) -> bool`
+fn f_is_positive(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: bool = i64::is_positive(a0_conv);
+        interp.data.push(Value::Boolean(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+/// `is_negative(i64) -> bool`
+fn f_is_negative(interp: &mut Interpreter) -> Result<(), Error> {
+    let arg0 = interp.data.pop().ok_or(Error::StackUndeflow)?;
+    if let Value::SignedInteger(a0) = arg0 {
+        let a0_conv = a0;
+        let r: bool = i64::is_negative(a0_conv);
+        interp.data.push(Value::Boolean(r));
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(
+            arg0.type_(),
+            vec![ValueType::SignedInteger],
+        ))
+    }
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new("signed::count_ones", f_count_ones));
+    interp.register_builtin(BuiltinFunction::new("signed::count_zeros", f_count_zeros));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::leading_zeros",
+        f_leading_zeros,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::trailing_zeros",
+        f_trailing_zeros,
+    ));
+    interp.register_builtin(BuiltinFunction::new("signed::rotate_left", f_rotate_left));
+    interp.register_builtin(BuiltinFunction::new("signed::rotate_right", f_rotate_right));
+    interp.register_builtin(BuiltinFunction::new("signed::swap_bytes", f_swap_bytes));
+    interp.register_builtin(BuiltinFunction::new("signed::reverse_bits", f_reverse_bits));
+    interp.register_builtin(BuiltinFunction::new("signed::checked_add", f_checked_add));
+    interp.register_builtin(BuiltinFunction::new("signed::checked_sub", f_checked_sub));
+    interp.register_builtin(BuiltinFunction::new("signed::checked_mul", f_checked_mul));
+    interp.register_builtin(BuiltinFunction::new("signed::checked_div", f_checked_div));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::checked_div_euc",
+        f_checked_div_euc,
+    ));
+    interp.register_builtin(BuiltinFunction::new("signed::checked_rem", f_checked_rem));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::checked_mod_euc",
+        f_checked_mod_euc,
+    ));
+    interp.register_builtin(BuiltinFunction::new("signed::checked_neg", f_checked_neg));
+    interp.register_builtin(BuiltinFunction::new("signed::checked_shl", f_checked_shl));
+    interp.register_builtin(BuiltinFunction::new("signed::checked_shr", f_checked_shr));
+    interp.register_builtin(BuiltinFunction::new("signed::checked_abs", f_checked_abs));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::saturating_add",
+        f_saturating_add,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::saturating_sub",
+        f_saturating_sub,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::saturating_mul",
+        f_saturating_mul,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::saturating_pow",
+        f_saturating_pow,
+    ));
+    interp.register_builtin(BuiltinFunction::new("signed::wrapping_add", f_wrapping_add));
+    interp.register_builtin(BuiltinFunction::new("signed::wrapping_sub", f_wrapping_sub));
+    interp.register_builtin(BuiltinFunction::new("signed::wrapping_mul", f_wrapping_mul));
+    interp.register_builtin(BuiltinFunction::new("signed::wrapping_div", f_wrapping_div));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::wrapping_div_euc",
+        f_wrapping_div_euc,
+    ));
+    interp.register_builtin(BuiltinFunction::new("signed::wrapping_rem", f_wrapping_rem));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::wrapping_mod_euc",
+        f_wrapping_mod_euc,
+    ));
+    interp.register_builtin(BuiltinFunction::new("signed::wrapping_neg", f_wrapping_neg));
+    interp.register_builtin(BuiltinFunction::new("signed::wrapping_shl", f_wrapping_shl));
+    interp.register_builtin(BuiltinFunction::new("signed::wrapping_shr", f_wrapping_shr));
+    interp.register_builtin(BuiltinFunction::new("signed::wrapping_abs", f_wrapping_abs));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::overflowing_add",
+        f_overflowing_add,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::overflowing_sub",
+        f_overflowing_sub,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::overflowing_mul",
+        f_overflowing_mul,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::overflowing_div",
+        f_overflowing_div,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::overflowing_div_euc",
+        f_overflowing_div_euc,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::overflowing_rem",
+        f_overflowing_rem,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::overflowing_mod_euc",
+        f_overflowing_mod_euc,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::overflowing_neg",
+        f_overflowing_neg,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::overflowing_shl",
+        f_overflowing_shl,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::overflowing_shr",
+        f_overflowing_shr,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "signed::overflowing_abs",
+        f_overflowing_abs,
+    ));
+    interp.register_builtin(BuiltinFunction::new("signed::div_euc", f_div_euc));
+    interp.register_builtin(BuiltinFunction::new("signed::mod_euc", f_mod_euc));
+    interp.register_builtin(BuiltinFunction::new("signed::abs", f_abs));
+    interp.register_builtin(BuiltinFunction::new("signed::signum", f_signum));
+    interp.register_builtin(BuiltinFunction::new("signed::is_positive", f_is_positive));
+    interp.register_builtin(BuiltinFunction::new("signed::is_negative", f_is_negative));
+}
@@ -0,0 +1,70 @@
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::value::{BuiltinFunction, HeapPointer, Value, ValueType};
+
+use super::args::pop;
+
+fn pop_pointer(interp: &mut Interpreter) -> Result<HeapPointer, Error> {
+    match pop(interp)? {
+        Value::Pointer(p) => Ok(p),
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::Pointer],
+        )),
+    }
+}
+
+/// (a -- ptr)
+fn f_alloc(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop(interp)?;
+    let ptr = interp.alloc(a0);
+    interp.data.push(Value::Pointer(ptr));
+    Ok(())
+}
+
+/// (ptr -- a)
+fn f_load(interp: &mut Interpreter) -> Result<(), Error> {
+    let ptr = pop_pointer(interp)?;
+    let value = interp.heap.load(ptr)?;
+    interp.data.push(value);
+    Ok(())
+}
+
+/// (a ptr -- )
+fn f_store(interp: &mut Interpreter) -> Result<(), Error> {
+    let ptr = pop_pointer(interp)?;
+    let a0 = pop(interp)?;
+    interp.heap.store(ptr, a0)
+}
+
+/// (ptr -- )
+fn f_free(interp: &mut Interpreter) -> Result<(), Error> {
+    let ptr = pop_pointer(interp)?;
+    interp.heap.free(ptr)
+}
+
+/// ( -- freed) Runs the garbage collector
+fn f_gc(interp: &mut Interpreter) -> Result<(), Error> {
+    let freed = interp.collect_garbage();
+    interp.data.push(Value::Integer(freed as u64));
+    Ok(())
+}
+
+/// ( -- live allocated collected collections)
+fn f_heapstats(interp: &mut Interpreter) -> Result<(), Error> {
+    let stats = interp.heap.stats();
+    interp.data.push(Value::Integer(stats.live as u64));
+    interp.data.push(Value::Integer(stats.allocated as u64));
+    interp.data.push(Value::Integer(stats.collected as u64));
+    interp.data.push(Value::Integer(stats.collections as u64));
+    Ok(())
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new("alloc", f_alloc));
+    interp.register_builtin(BuiltinFunction::new("load", f_load));
+    interp.register_builtin(BuiltinFunction::new("store", f_store));
+    interp.register_builtin(BuiltinFunction::new("free", f_free));
+    interp.register_builtin(BuiltinFunction::new("gc", f_gc));
+    interp.register_builtin(BuiltinFunction::new("heapstats", f_heapstats));
+}
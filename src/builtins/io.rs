@@ -0,0 +1,34 @@
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::value::{BuiltinFunction, Value};
+
+use super::args::pop;
+
+/// Strings are written as-is, big integers in decimal
+/// and other values in their debug representation
+fn format_value(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::BigInteger(v) => v.to_string(),
+        v => format!("{:?}", v),
+    }
+}
+
+/// (a -- )
+fn f_print(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop(interp)?;
+    print!("{}", format_value(&a0));
+    Ok(())
+}
+
+/// (a -- )
+fn f_println(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop(interp)?;
+    println!("{}", format_value(&a0));
+    Ok(())
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new("print", f_print));
+    interp.register_builtin(BuiltinFunction::new("println", f_println));
+}
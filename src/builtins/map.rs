@@ -0,0 +1,87 @@
+//! Hash maps keyed by any value
+
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::value::{BuiltinFunction, Value, ValueMap};
+
+use super::args::{pop, pop_map};
+
+/// (-- map)
+fn f_new(interp: &mut Interpreter) -> Result<(), Error> {
+    interp.data.push(Value::Map(ValueMap::default()));
+    Ok(())
+}
+
+/// (map k v -- map') Replaces any previous value of `k`
+fn f_insert(interp: &mut Interpreter) -> Result<(), Error> {
+    let a2 = pop(interp)?;
+    let a1 = pop(interp)?;
+    let mut a0 = pop_map(interp)?;
+    a0.0.insert(a1, a2);
+    interp.data.push(Value::Map(a0));
+    Ok(())
+}
+
+/// (map k -- v true | false)
+fn f_get(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop(interp)?;
+    let mut a0 = pop_map(interp)?;
+    let r = a0.0.remove(&a1);
+    let found = r.is_some();
+    if let Some(value) = r {
+        interp.data.push(value);
+    }
+    interp.data.push(Value::Boolean(found));
+    Ok(())
+}
+
+/// (map k -- map') Does nothing if `k` is not present
+fn f_remove(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop(interp)?;
+    let mut a0 = pop_map(interp)?;
+    a0.0.remove(&a1);
+    interp.data.push(Value::Map(a0));
+    Ok(())
+}
+
+/// (map k -- bool)
+fn f_contains(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop(interp)?;
+    let a0 = pop_map(interp)?;
+    interp.data.push(Value::Boolean(a0.0.contains_key(&a1)));
+    Ok(())
+}
+
+/// (map -- arr) In unspecified order
+fn f_keys(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_map(interp)?;
+    let keys = a0.0.into_iter().map(|(k, _)| k).collect();
+    interp.data.push(Value::Array(keys));
+    Ok(())
+}
+
+/// (map -- arr) In unspecified order
+fn f_values(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_map(interp)?;
+    let values = a0.0.into_iter().map(|(_, v)| v).collect();
+    interp.data.push(Value::Array(values));
+    Ok(())
+}
+
+/// (map -- n)
+fn f_size(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_map(interp)?;
+    interp.data.push(Value::Integer(a0.0.len() as u64));
+    Ok(())
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new("map::new", f_new));
+    interp.register_builtin(BuiltinFunction::new("map::insert", f_insert));
+    interp.register_builtin(BuiltinFunction::new("map::get", f_get));
+    interp.register_builtin(BuiltinFunction::new("map::remove", f_remove));
+    interp.register_builtin(BuiltinFunction::new("map::contains", f_contains));
+    interp.register_builtin(BuiltinFunction::new("map::keys", f_keys));
+    interp.register_builtin(BuiltinFunction::new("map::values", f_values));
+    interp.register_builtin(BuiltinFunction::new("map::size", f_size));
+}
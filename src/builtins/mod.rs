@@ -1,18 +1,47 @@
+mod args;
+mod arithmetic;
+mod array;
 mod boolean;
+mod combinators;
 mod compare;
 mod control_flow;
+mod convert;
 mod debug;
+mod error;
+mod float;
 mod generated;
+mod heap;
+mod io;
+mod map;
 mod stack;
+mod string;
+mod structs;
+mod symbol;
+mod types;
 
 use crate::interpreter::Interpreter;
 
 pub fn register_all(interp: &mut Interpreter) {
     debug::register_all(interp);
     stack::register_all(interp);
+    arithmetic::register_all(interp);
+    array::register_all(interp);
     compare::register_all(interp);
     boolean::register_all(interp);
     control_flow::register_all(interp);
+    combinators::register_all(interp);
+    convert::register_all(interp);
+    error::register_all(interp);
+    float::register_all(interp);
+    heap::register_all(interp);
+    io::register_all(interp);
+    map::register_all(interp);
+    string::register_all(interp);
+    structs::register_all(interp);
+    symbol::register_all(interp);
+    types::register_all(interp);
 
+    generated::float::register_all(interp);
     generated::int::register_all(interp);
+    generated::signed::register_all(interp);
 }
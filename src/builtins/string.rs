@@ -0,0 +1,111 @@
+use num_bigint::BigUint;
+
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::value::{BuiltinFunction, Value, ValueType};
+
+use super::args::{pop, pop_string, pop_usize};
+use super::arithmetic::normalize;
+
+/// (a b -- ab)
+fn f_concat(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop_string(interp)?;
+    let a0 = pop_string(interp)?;
+    interp.data.push(Value::String(a0 + &a1));
+    Ok(())
+}
+
+/// (s -- n) Length in characters
+fn f_length(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_string(interp)?;
+    interp.data.push(Value::Integer(a0.chars().count() as u64));
+    Ok(())
+}
+
+/// (s start end -- sub) Character range `start..end`
+fn f_slice(interp: &mut Interpreter) -> Result<(), Error> {
+    let end = pop_usize(interp)?;
+    let start = pop_usize(interp)?;
+    let a0 = pop_string(interp)?;
+
+    let length = a0.chars().count();
+    if end > length {
+        return Err(Error::IndexOutOfBounds(end, length));
+    }
+    if start > end {
+        return Err(Error::InvalidRange(start, end));
+    }
+
+    let sub: String = a0.chars().skip(start).take(end - start).collect();
+    interp.data.push(Value::String(sub));
+    Ok(())
+}
+
+/// (s needle -- index true | false) Character index of the first match
+fn f_find(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop_string(interp)?;
+    let a0 = pop_string(interp)?;
+
+    let r = a0
+        .find(&a1)
+        .map(|byte_index| a0[..byte_index].chars().count());
+    if let Some(v) = r {
+        interp.data.push(Value::Integer(v as u64));
+    }
+    interp.data.push(Value::Boolean(r.is_some()));
+    Ok(())
+}
+
+/// (s separator -- parts... count)
+fn f_split(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop_string(interp)?;
+    let a0 = pop_string(interp)?;
+
+    let parts: Vec<Value> = a0
+        .split(a1.as_str())
+        .map(|part| Value::String(part.to_owned()))
+        .collect();
+    let count = parts.len() as u64;
+    interp.data.extend(parts);
+    interp.data.push(Value::Integer(count));
+    Ok(())
+}
+
+/// (n -- s)
+fn f_from_integer(interp: &mut Interpreter) -> Result<(), Error> {
+    let text = match pop(interp)? {
+        Value::Integer(v) => v.to_string(),
+        Value::BigInteger(v) => v.to_string(),
+        other => {
+            return Err(Error::WrongArgumentType(
+                other.type_(),
+                vec![ValueType::Integer, ValueType::BigInteger],
+            ))
+        },
+    };
+    interp.data.push(Value::String(text));
+    Ok(())
+}
+
+/// (s -- n true | false) Accepts decimal digits only
+fn f_to_integer(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_string(interp)?;
+    let digits = !a0.is_empty() && a0.chars().all(|c| c.is_ascii_digit());
+    let r = a0.parse::<BigUint>().ok().filter(|_| digits);
+    let found = r.is_some();
+    if let Some(v) = r {
+        interp.data.push(normalize(v));
+    }
+    interp.data.push(Value::Boolean(found));
+    Ok(())
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new("string::concat", f_concat));
+    interp.register_builtin(BuiltinFunction::new("string::length", f_length));
+    interp.register_builtin(BuiltinFunction::new("string::slice", f_slice));
+    interp.register_builtin(BuiltinFunction::new("string::find", f_find));
+    interp.register_builtin(BuiltinFunction::new("string::split", f_split));
+    interp.register_builtin(BuiltinFunction::new("string::from_integer", f_from_integer));
+    interp.register_builtin(BuiltinFunction::new("string::to_integer", f_to_integer));
+}
@@ -0,0 +1,208 @@
+//! User-defined structs
+//!
+//! `"Point" "x:Integer y:Integer" defstruct` registers the definition
+//! and generates `Point::new`, `Point::x` and `Point::set_x` etc. into
+//! the current scope. The generated functions call the generic
+//! `::struct::*` builtins with the definition and field indices.
+
+use crate::error::Error;
+use crate::interpreter::{parse_literal, Interpreter};
+use crate::namespace::AbsoluteSymbolPath;
+use crate::scanner::{is_identifier, Spanned, Token};
+use crate::value::{
+    BuiltinFunction, StructDefinitionIndex, UserFunction, UserStruct, UserStructMeta,
+    UserStructMetaField, Value, ValueType,
+};
+
+use super::args::{pop, pop_string, pop_usize};
+
+fn parse_fields(spec: &str) -> Result<Vec<UserStructMetaField>, Error> {
+    let mut fields: Vec<UserStructMetaField> = Vec::new();
+    for item in spec.split_whitespace() {
+        let mut halfs = item.splitn(2, ':');
+        let name = halfs.next().unwrap_or("");
+        let type_name = halfs.next().ok_or_else(|| {
+            Error::InvalidStructDefinition(format!("Field {:?} has no type", item))
+        })?;
+
+        if !is_identifier(name) || name.contains("::") {
+            return Err(Error::InvalidStructDefinition(format!(
+                "Invalid field name {:?}",
+                name
+            )));
+        }
+        if fields.iter().any(|f| f.name == name) {
+            return Err(Error::InvalidStructDefinition(format!(
+                "Duplicate field {:?}",
+                name
+            )));
+        }
+        let ttag = ValueType::from_name(type_name).ok_or_else(|| {
+            Error::InvalidStructDefinition(format!("Unknown type {:?}", type_name))
+        })?;
+
+        fields.push(UserStructMetaField {
+            name: name.to_owned(),
+            ttag,
+        });
+    }
+    Ok(fields)
+}
+
+/// Function body pushing the given integers and calling a builtin
+fn generated_body(args: &[usize], builtin: &str) -> Value {
+    let mut tokens: Vec<Spanned> = args
+        .iter()
+        .map(|a| Token::Identifier(a.to_string()).into())
+        .collect();
+    tokens.push(Token::Identifier(builtin.to_owned()).into());
+    Value::Function(UserFunction::new(AbsoluteSymbolPath::root(), tokens))
+}
+
+fn check_field_type(field: &UserStructMetaField, value: &Value) -> Result<(), Error> {
+    if value.type_() == field.ttag {
+        Ok(())
+    } else {
+        Err(Error::WrongArgumentType(value.type_(), vec![field.ttag]))
+    }
+}
+
+fn pop_definition(interp: &mut Interpreter) -> Result<StructDefinitionIndex, Error> {
+    let index = pop_usize(interp)?;
+    if index < interp.structs.len() {
+        Ok(StructDefinitionIndex(index))
+    } else {
+        Err(Error::IndexOutOfBounds(index, interp.structs.len()))
+    }
+}
+
+/// Pops an instance of the given definition
+fn pop_instance(
+    interp: &mut Interpreter,
+    definition: StructDefinitionIndex,
+) -> Result<UserStruct, Error> {
+    match pop(interp)? {
+        Value::UserDefined(instance) => if instance.definition == definition {
+            Ok(instance)
+        } else {
+            Err(Error::WrongStructType(
+                interp.structs[instance.definition.0].name.clone(),
+                interp.structs[definition.0].name.clone(),
+            ))
+        },
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::UserDefined],
+        )),
+    }
+}
+
+/// (name spec -- )
+fn f_defstruct(interp: &mut Interpreter) -> Result<(), Error> {
+    let spec = pop_string(interp)?;
+    let name = pop_string(interp)?;
+
+    // Names read as literals are rejected when used, e.g. `1x::new`
+    let literal = match parse_literal(&name) {
+        Ok(None) => false,
+        _ => true,
+    };
+    if literal || !is_identifier(&name) {
+        return Err(Error::InvalidStructDefinition(format!(
+            "Invalid struct name {:?}",
+            name
+        )));
+    }
+    let fields = parse_fields(&spec)?;
+
+    let def = interp.structs.len();
+    for (i, field) in fields.iter().enumerate() {
+        let getter = generated_body(&[def, i], "::struct::get");
+        let setter = generated_body(&[def, i], "::struct::set");
+        interp.define(&format!("{}::{}", name, field.name), getter);
+        interp.define(&format!("{}::set_{}", name, field.name), setter);
+    }
+    interp.define(
+        &format!("{}::new", name),
+        generated_body(&[def], "::struct::new"),
+    );
+
+    interp.structs.push(UserStructMeta { name, fields });
+    Ok(())
+}
+
+/// (fields... def -- instance)
+fn f_struct_new(interp: &mut Interpreter) -> Result<(), Error> {
+    let definition = pop_definition(interp)?;
+    let meta = interp.structs[definition.0].clone();
+
+    let mut fields: Vec<Value> = Vec::with_capacity(meta.fields.len());
+    for field in meta.fields.iter().rev() {
+        let value = pop(interp)?;
+        check_field_type(field, &value)?;
+        fields.push(value);
+    }
+    fields.reverse();
+
+    interp
+        .data
+        .push(Value::UserDefined(UserStruct { definition, fields }));
+    Ok(())
+}
+
+/// (instance def field -- value)
+fn f_struct_get(interp: &mut Interpreter) -> Result<(), Error> {
+    let field = pop_usize(interp)?;
+    let definition = pop_definition(interp)?;
+    let instance = pop_instance(interp, definition)?;
+
+    let value = instance
+        .fields
+        .get(field)
+        .cloned()
+        .ok_or_else(|| Error::IndexOutOfBounds(field, instance.fields.len()))?;
+    interp.data.push(value);
+    Ok(())
+}
+
+/// (instance value def field -- instance)
+fn f_struct_set(interp: &mut Interpreter) -> Result<(), Error> {
+    let field = pop_usize(interp)?;
+    let definition = pop_definition(interp)?;
+    let value = pop(interp)?;
+    let mut instance = pop_instance(interp, definition)?;
+
+    let length = instance.fields.len();
+    let meta_field = interp.structs[definition.0]
+        .fields
+        .get(field)
+        .ok_or(Error::IndexOutOfBounds(field, length))?;
+    check_field_type(meta_field, &value)?;
+
+    instance.fields[field] = value;
+    interp.data.push(Value::UserDefined(instance));
+    Ok(())
+}
+
+/// (instance -- name)
+fn f_struct_name(interp: &mut Interpreter) -> Result<(), Error> {
+    match pop(interp)? {
+        Value::UserDefined(instance) => {
+            let name = interp.structs[instance.definition.0].name.clone();
+            interp.data.push(Value::String(name));
+            Ok(())
+        },
+        other => Err(Error::WrongArgumentType(
+            other.type_(),
+            vec![ValueType::UserDefined],
+        )),
+    }
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new("defstruct", f_defstruct));
+    interp.register_builtin(BuiltinFunction::new("struct::new", f_struct_new));
+    interp.register_builtin(BuiltinFunction::new("struct::get", f_struct_get));
+    interp.register_builtin(BuiltinFunction::new("struct::set", f_struct_set));
+    interp.register_builtin(BuiltinFunction::new("struct::name", f_struct_name));
+}
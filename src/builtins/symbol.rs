@@ -0,0 +1,80 @@
+//! Runtime access to definitions through quoted names
+
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::scanner::is_identifier;
+use crate::value::{BuiltinFunction, Value};
+
+use super::args::{pop, pop_string, pop_symbol};
+
+/// (sym -- value) Pushes the definition without executing it
+fn f_lookup(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_symbol(interp)?;
+    let value = interp.lookup(&a0)?;
+    interp.data.push(value);
+    Ok(())
+}
+
+/// (value sym --) Same as `/name`, but with a computed name
+fn f_define(interp: &mut Interpreter) -> Result<(), Error> {
+    let a1 = pop_symbol(interp)?;
+    let a0 = pop(interp)?;
+    interp.define(&a1, a0);
+    Ok(())
+}
+
+/// (sym -- bool)
+fn f_is_defined(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_symbol(interp)?;
+    let r = interp.lookup(&a0).is_ok();
+    interp.data.push(Value::Boolean(r));
+    Ok(())
+}
+
+/// (sym --) Removes a user definition from the current namespace
+fn f_forget(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_symbol(interp)?;
+    interp.forget(&a0)?;
+    Ok(())
+}
+
+/// (sym -- str) Doc comment of the definition, empty if undocumented
+fn f_doc(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_symbol(interp)?;
+    let doc = interp.doc(&a0)?.unwrap_or_default();
+    interp.data.push(Value::String(doc));
+    Ok(())
+}
+
+/// (str -- sym)
+fn f_string_to_symbol(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_string(interp)?;
+    if !is_identifier(&a0) {
+        return Err(Error::InvalidName(a0));
+    }
+    interp.data.push(Value::Symbol(a0));
+    Ok(())
+}
+
+/// (sym -- str)
+fn f_symbol_to_string(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop_symbol(interp)?;
+    interp.data.push(Value::String(a0));
+    Ok(())
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new("lookup", f_lookup));
+    interp.register_builtin(BuiltinFunction::new("define", f_define));
+    interp.register_builtin(BuiltinFunction::new("defined?", f_is_defined));
+    interp.register_builtin(BuiltinFunction::new("forget", f_forget));
+    interp.register_builtin(BuiltinFunction::new("doc", f_doc));
+    interp.register_builtin(BuiltinFunction::new(
+        "string::to_symbol",
+        f_string_to_symbol,
+    ));
+    interp.register_builtin(BuiltinFunction::new(
+        "symbol::to_string",
+        f_symbol_to_string,
+    ));
+}
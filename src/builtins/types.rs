@@ -0,0 +1,21 @@
+//! Types as values, named like `Integer` or `Function`
+
+use crate::error::Error;
+use crate::interpreter::Interpreter;
+use crate::value::{BuiltinFunction, Value, ValueType};
+
+use super::args::pop;
+
+/// (v -- type)
+fn f_type_of(interp: &mut Interpreter) -> Result<(), Error> {
+    let a0 = pop(interp)?;
+    interp.data.push(Value::Type(a0.type_()));
+    Ok(())
+}
+
+pub fn register_all(interp: &mut Interpreter) {
+    interp.register_builtin(BuiltinFunction::new("type_of", f_type_of));
+    for &ttag in ValueType::ALL.iter() {
+        interp.register_constant(&format!("{:?}", ttag), Value::Type(ttag));
+    }
+}
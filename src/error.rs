@@ -1,11 +1,47 @@
-use crate::namespace::SymbolPath;
-use crate::value::ValueType;
+use std::fmt;
+
+use crate::namespace::{AbsoluteSymbolPath, SymbolPath};
+use crate::scanner::Span;
+use crate::value::{HeapPointer, Value, ValueType};
 
 #[derive(Debug, Clone)]
 #[must_use]
 pub enum SyntaxError {
     UnexpectedEndOfInput,
     AssignToEmpty,
+    UnknownEscape(char),
+    EmptySymbol,
+    InvalidLocalName(String),
+    /// Character literals must contain exactly one character
+    InvalidCharLiteral(String),
+    /// `{` without a matching `}`
+    UnclosedFunction,
+    /// `[` without a matching `]`
+    UnclosedArray,
+    /// `}` without a matching `{`
+    UnmatchedFunctionEnd,
+    /// `]` without a matching `[`
+    UnmatchedArrayEnd,
+    /// `}` closing a `[`, or `]` closing a `{`
+    MismatchedDelimiter,
+    /// `(` without a matching `)`
+    UnterminatedComment,
+    /// `#` without a namespace name
+    EmptyNamespace,
+    /// Path with an empty name between `::` separators
+    MalformedPath(String),
+}
+impl SyntaxError {
+    /// True if more input could make the code valid
+    pub fn is_incomplete(&self) -> bool {
+        match self {
+            SyntaxError::UnexpectedEndOfInput
+            | SyntaxError::UnterminatedComment
+            | SyntaxError::UnclosedFunction
+            | SyntaxError::UnclosedArray => true,
+            _ => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -13,10 +49,152 @@ pub enum SyntaxError {
 pub enum Error {
     InvalidSyntax(SyntaxError),
     FunctionEndOutsideFunction,
+    ArrayEndOutsideArray,
     StackUndeflow,
+    /// NotEnoughArguments(required, available)
+    NotEnoughArguments(usize, usize),
+    /// Too many nested function calls
+    CallDepthExceeded,
     NameNotDefined(SymbolPath),
+    /// Only user definitions directly in the current namespace can be forgotten
+    CannotForget(AbsoluteSymbolPath),
+    /// Numeric literal that does not fit its type
+    LiteralOutOfRange(String),
+    /// Name starting with a digit that is not a valid numeric literal
+    InvalidLiteral(String),
+    /// Definitions cannot have names that are read as literals
+    AssignToLiteral(String),
+    /// Locals can only be declared as the first token of a function
+    MisplacedLocals,
+    /// Text that cannot be used as a name
+    InvalidName(String),
     IntegerOverflow,
+    DivisionByZero,
+    /// Pointer was never allocated or has already been freed
+    InvalidPointer(HeapPointer),
+    /// IndexOutOfBounds(index, length)
+    IndexOutOfBounds(usize, usize),
+    /// Range that ends before it starts, InvalidRange(start, end)
+    InvalidRange(usize, usize),
     /// WrongArgumentType(actual, allowed)
     WrongArgumentType(ValueType, Vec<ValueType>),
     AssertionFailed,
+    /// Value raised by `throw`
+    Thrown(Value),
+    /// `return` leaving a function from inside a builtin such as `each`,
+    /// handled by the interpreter and never reported
+    Return,
+    /// No `cond` predicate held and there was no default branch
+    NoMatchingBranch,
+    /// No `case` pattern matched the value and there was no default branch
+    NoMatchingCase(Value),
+    /// Branches are `[ pred body ]` or a default `[ body ]`, InvalidBranch(length)
+    InvalidBranch(usize),
+    InvalidStructDefinition(String),
+    /// WrongStructType(actual, expected)
+    WrongStructType(String, String),
+}
+impl Error {
+    /// Name of the variant, given to `try` handlers as the error kind
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Error::InvalidSyntax(..) => "InvalidSyntax",
+            Error::FunctionEndOutsideFunction => "FunctionEndOutsideFunction",
+            Error::ArrayEndOutsideArray => "ArrayEndOutsideArray",
+            Error::StackUndeflow => "StackUndeflow",
+            Error::NotEnoughArguments(..) => "NotEnoughArguments",
+            Error::CallDepthExceeded => "CallDepthExceeded",
+            Error::NameNotDefined(..) => "NameNotDefined",
+            Error::CannotForget(..) => "CannotForget",
+            Error::LiteralOutOfRange(..) => "LiteralOutOfRange",
+            Error::InvalidLiteral(..) => "InvalidLiteral",
+            Error::AssignToLiteral(..) => "AssignToLiteral",
+            Error::MisplacedLocals => "MisplacedLocals",
+            Error::InvalidName(..) => "InvalidName",
+            Error::IntegerOverflow => "IntegerOverflow",
+            Error::DivisionByZero => "DivisionByZero",
+            Error::InvalidPointer(..) => "InvalidPointer",
+            Error::IndexOutOfBounds(..) => "IndexOutOfBounds",
+            Error::InvalidRange(..) => "InvalidRange",
+            Error::WrongArgumentType(..) => "WrongArgumentType",
+            Error::AssertionFailed => "AssertionFailed",
+            Error::Thrown(..) => "Thrown",
+            Error::Return => "Return",
+            Error::NoMatchingBranch => "NoMatchingBranch",
+            Error::NoMatchingCase(..) => "NoMatchingCase",
+            Error::InvalidBranch(..) => "InvalidBranch",
+            Error::InvalidStructDefinition(..) => "InvalidStructDefinition",
+            Error::WrongStructType(..) => "WrongStructType",
+        }
+    }
+}
+
+/// Function that was running when an error happened
+#[derive(Debug, Clone)]
+pub struct TraceEntry {
+    /// `None` for anonymous functions
+    pub name: Option<AbsoluteSymbolPath>,
+    pub namespace: AbsoluteSymbolPath,
+    /// Token the function was executing
+    pub span: Option<Span>,
+}
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "::{}", name.0.join("::"))?,
+            None => write!(f, "<anonymous> in ::{}", self.namespace.0.join("::"))?,
+        };
+        match &self.span {
+            Some(span) => write!(f, " at {}", span),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Error returned from `Interpreter::execute`, with where it happened
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Failure {
+    pub error: Error,
+    /// Active functions, innermost first
+    pub backtrace: Vec<TraceEntry>,
+    /// Top-level token being executed, or where parsing failed
+    pub span: Option<Span>,
+}
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Error: {:?}", self.error)?;
+        for entry in &self.backtrace {
+            writeln!(f, "    in {}", entry)?;
+        }
+        match &self.span {
+            Some(span) => write!(f, "    at {}", span),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Syntax error found while parsing, with where it was found
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub error: SyntaxError,
+    pub span: Option<Span>,
+}
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Syntax error: {:?}", self.error)?;
+        match &self.span {
+            Some(span) => write!(f, " at {}", span),
+            None => Ok(()),
+        }
+    }
+}
+impl From<Diagnostic> for Failure {
+    fn from(diagnostic: Diagnostic) -> Self {
+        Failure {
+            error: Error::InvalidSyntax(diagnostic.error),
+            backtrace: Vec::new(),
+            span: diagnostic.span,
+        }
+    }
 }
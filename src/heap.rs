@@ -0,0 +1,163 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::Error;
+use crate::scanner::{Spanned, Token};
+use crate::value::{HeapPointer, Value};
+
+/// Allocations between collections never go below this
+const MIN_GC_THRESHOLD: usize = 1024;
+
+/// Anything that can hold heap pointers
+pub trait Trace {
+    /// Push all directly held pointers to `out`
+    fn trace(&self, out: &mut Vec<HeapPointer>);
+}
+impl Trace for Value {
+    fn trace(&self, out: &mut Vec<HeapPointer>) {
+        match self {
+            Value::Pointer(p) => out.push(*p),
+            Value::Array(items) => {
+                for item in items {
+                    item.trace(out);
+                }
+            },
+            Value::Map(map) => {
+                for (key, value) in &map.0 {
+                    key.trace(out);
+                    value.trace(out);
+                }
+            },
+            Value::Function(f) => {
+                for token in &f.body {
+                    token.trace(out);
+                }
+                for value in &f.captured {
+                    value.trace(out);
+                }
+                for (_, value) in &f.locals {
+                    value.trace(out);
+                }
+            },
+            Value::UserDefined(instance) => {
+                for field in &instance.fields {
+                    field.trace(out);
+                }
+            },
+            _ => {},
+        }
+    }
+}
+impl Trace for Spanned {
+    fn trace(&self, out: &mut Vec<HeapPointer>) {
+        if let Token::Literal(value) = &self.token {
+            value.trace(out);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeapStats {
+    /// Currently allocated cells
+    pub live: usize,
+    /// Cells allocated in total
+    pub allocated: usize,
+    /// Cells freed by the collector in total
+    pub collected: usize,
+    /// Number of collections run
+    pub collections: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Heap {
+    cells: HashMap<HeapPointer, Value>,
+    /// Pointers are never reused, so that dangling ones can be detected
+    next_pointer: usize,
+    /// Allocations since the last collection
+    allocs_since_gc: usize,
+    /// Collect when `allocs_since_gc` reaches this
+    gc_threshold: usize,
+    stats: HeapStats,
+}
+impl Heap {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            next_pointer: 0,
+            allocs_since_gc: 0,
+            gc_threshold: MIN_GC_THRESHOLD,
+            stats: HeapStats::default(),
+        }
+    }
+
+    pub fn alloc(&mut self, value: Value) -> HeapPointer {
+        let ptr = HeapPointer(self.next_pointer);
+        self.next_pointer += 1;
+        self.allocs_since_gc += 1;
+        self.stats.allocated += 1;
+        self.cells.insert(ptr, value);
+        ptr
+    }
+
+    pub fn load(&self, ptr: HeapPointer) -> Result<Value, Error> {
+        self.cells
+            .get(&ptr)
+            .cloned()
+            .ok_or(Error::InvalidPointer(ptr))
+    }
+
+    pub fn store(&mut self, ptr: HeapPointer, value: Value) -> Result<(), Error> {
+        match self.cells.get_mut(&ptr) {
+            Some(cell) => {
+                *cell = value;
+                Ok(())
+            },
+            None => Err(Error::InvalidPointer(ptr)),
+        }
+    }
+
+    pub fn free(&mut self, ptr: HeapPointer) -> Result<(), Error> {
+        self.cells
+            .remove(&ptr)
+            .map(|_| ())
+            .ok_or(Error::InvalidPointer(ptr))
+    }
+
+    pub fn stats(&self) -> HeapStats {
+        HeapStats {
+            live: self.cells.len(),
+            ..self.stats
+        }
+    }
+
+    /// True when enough allocations have happened since the last collection
+    pub fn should_collect(&self) -> bool {
+        self.allocs_since_gc >= self.gc_threshold
+    }
+
+    /// Mark-and-sweep collection, frees every cell not reachable from `roots`.
+    /// Returns the number of freed cells.
+    pub fn collect(&mut self, roots: Vec<HeapPointer>) -> usize {
+        let mut marked: HashSet<HeapPointer> = HashSet::new();
+        let mut pending = roots;
+        while let Some(ptr) = pending.pop() {
+            if marked.contains(&ptr) {
+                continue;
+            }
+            // Dangling pointers are allowed in roots, they are simply not followed
+            if let Some(value) = self.cells.get(&ptr) {
+                marked.insert(ptr);
+                value.trace(&mut pending);
+            }
+        }
+
+        let before = self.cells.len();
+        self.cells.retain(|ptr, _| marked.contains(ptr));
+        let freed = before - self.cells.len();
+
+        self.allocs_since_gc = 0;
+        self.gc_threshold = MIN_GC_THRESHOLD.max(self.cells.len());
+        self.stats.collected += freed;
+        self.stats.collections += 1;
+        freed
+    }
+}
@@ -1,10 +1,109 @@
-use std::collections::HashMap;
+use std::collections::HashSet;
+use std::mem;
 
 use crate::builtins;
-use crate::error::Error;
+use crate::error::{Error, Failure, TraceEntry};
+use crate::heap::{Heap, Trace};
 use crate::namespace::{AbsoluteSymbolPath, Namespace, SymbolPath};
-use crate::scanner::{scan_token, Token};
-use crate::value::{BuiltinFunction, HeapPointer, Value};
+use crate::parser::Node;
+use crate::scanner::{Span, Spanned, Token};
+use crate::value::{BuiltinFunction, HeapPointer, UserFunction, UserStructMeta, Value};
+
+/// Calls nested deeper than this are assumed to be runaway recursion
+const MAX_CALL_DEPTH: usize = 10_000;
+/// Limit for builtins running code that runs builtins running code,
+/// each level of which uses native stack
+const MAX_NESTED_CALLS: usize = 256;
+
+/// Boolean literal, integer literal, signed if it has an explicit sign,
+/// or a float literal with a decimal point and/or an exponent.
+///
+/// Integers may use a `0x`, `0b` or `0o` radix prefix, and any number
+/// may contain `_` digit separators. Names that do not start with a digit
+/// (after the sign) are not literals. Anything else that starts with a
+/// digit is an error, as is a number that does not fit its type.
+pub(crate) fn parse_literal(ident: &str) -> Result<Option<Value>, Error> {
+    match ident {
+        "true" => return Ok(Some(Value::Boolean(true))),
+        "false" => return Ok(Some(Value::Boolean(false))),
+        _ => {},
+    }
+
+    let signed = ident.starts_with('-') || ident.starts_with('+');
+    let sign = if ident.starts_with('-') { "-" } else { "" };
+    let unsigned = if signed { &ident[1..] } else { ident };
+    // Do not accept `inf` or `NaN`, those are valid names
+    if !unsigned.starts_with(|c: char| c.is_ascii_digit()) {
+        return Ok(None);
+    }
+
+    let digits = unsigned.replace('_', "");
+    let (radix, digits) = match digits.get(..2) {
+        Some("0x") => (16, &digits[2..]),
+        Some("0b") => (2, &digits[2..]),
+        Some("0o") => (8, &digits[2..]),
+        _ => (10, &digits[..]),
+    };
+
+    if !digits.is_empty() && digits.chars().all(|c| c.is_digit(radix)) {
+        let value = if signed {
+            let text = format!("{}{}", sign, digits);
+            i64::from_str_radix(&text, radix)
+                .ok()
+                .map(Value::SignedInteger)
+        } else {
+            u64::from_str_radix(digits, radix).ok().map(Value::Integer)
+        };
+        return value
+            .map(Some)
+            .ok_or_else(|| Error::LiteralOutOfRange(ident.to_owned()));
+    }
+
+    if radix != 10 {
+        return Err(Error::InvalidLiteral(ident.to_owned()));
+    }
+    let text = format!("{}{}", sign, digits);
+    match text.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(Some(Value::Float(value))),
+        Ok(_) => Err(Error::LiteralOutOfRange(ident.to_owned())),
+        Err(_) => Err(Error::InvalidLiteral(ident.to_owned())),
+    }
+}
+
+/// Running function body
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// Name the function was called by, `None` for anonymous functions
+    pub name: Option<AbsoluteSymbolPath>,
+    /// Namespace the function was defined in
+    pub namespace: AbsoluteSymbolPath,
+    pub body: Vec<Spanned>,
+    /// Index of the next token in `body`
+    pub ip: usize,
+    /// Named values, later ones shadow earlier ones
+    pub locals: Vec<(String, Value)>,
+    /// Whether `return` stops at this frame, true for named functions and
+    /// for frames that replaced such a frame by a tail call
+    pub returns_here: bool,
+}
+impl Frame {
+    fn finished(&self) -> bool {
+        self.ip >= self.body.len()
+    }
+
+    /// Span of the token executed last
+    fn span(&self) -> Option<Span> {
+        let index = self.ip.checked_sub(1)?;
+        self.body.get(index)?.span.clone()
+    }
+}
+
+/// Control state to return to after a failed call
+pub(crate) struct Checkpoint {
+    call_depth: usize,
+    array_marks: usize,
+    pinned: usize,
+}
 
 #[derive(Debug, Clone)]
 pub struct Interpreter {
@@ -12,13 +111,30 @@ pub struct Interpreter {
     skip_next: bool,
 
     nesting: u32,
-    scan: Vec<Token>,
+    scan: Vec<Spanned>,
+
+    /// Data stack depths at the currently open array literals
+    array_marks: Vec<usize>,
+    /// Nesting depth of an array literal being skipped
+    skip_array: u32,
 
     pub(crate) data: Vec<Value>,
-    pub call: Vec<Token>,
+    frames: Vec<Frame>,
+    /// Frames below this belong to a caller waiting in `call_value`
+    call_floor: usize,
+    /// Number of `call_value` calls in progress, each one uses native stack
+    nested_calls: usize,
 
-    heap: HashMap<HeapPointer, Value>,
+    pub(crate) heap: Heap,
+    /// Values held by running builtins, kept alive for the garbage collector
+    pub(crate) pinned: Vec<Value>,
     dict: Namespace,
+    /// Names registered with the builtins, which `forget` refuses to remove
+    builtin_names: HashSet<AbsoluteSymbolPath>,
+    /// Doc comment waiting for the next `/name` definition
+    pending_doc: Option<String>,
+    /// Indexed by `StructDefinitionIndex`
+    pub(crate) structs: Vec<UserStructMeta>,
 }
 impl Interpreter {
     pub fn new() -> Self {
@@ -27,10 +143,18 @@ impl Interpreter {
             skip_next: false,
             nesting: 0,
             scan: Vec::new(),
+            array_marks: Vec::new(),
+            skip_array: 0,
             data: Vec::new(),
-            call: Vec::new(),
-            heap: HashMap::new(),
+            frames: Vec::new(),
+            call_floor: 0,
+            nested_calls: 0,
+            heap: Heap::new(),
+            pinned: Vec::new(),
             dict: Namespace::new(),
+            builtin_names: HashSet::new(),
+            pending_doc: None,
+            structs: Vec::new(),
         }
     }
 
@@ -49,22 +173,117 @@ impl Interpreter {
     }
 
     pub(crate) fn register_builtin(&mut self, bf: BuiltinFunction) {
-        let sp = SymbolPath::from_str(&bf.name()).realize(&AbsoluteSymbolPath::root());
+        let name = bf.name();
+        self.register_constant(&name, Value::BuiltinFunction(bf));
+    }
+
+    pub(crate) fn register_constant(&mut self, name: &str, value: Value) {
+        let sp = SymbolPath::from_str(name).realize(&AbsoluteSymbolPath::root());
         debug_assert!(self.dict.resolve(&sp) == None);
-        self.dict.insert(sp, Value::BuiltinFunction(bf));
+        self.builtin_names.insert(sp.clone());
+        self.dict.insert(sp, value);
+    }
+
+    /// Pointers directly reachable from the interpreter state
+    fn gc_roots(&self) -> Vec<HeapPointer> {
+        let mut roots = Vec::new();
+        let values = self.data.iter().chain(self.pinned.iter());
+        for value in values.chain(self.dict.values()) {
+            value.trace(&mut roots);
+        }
+        for frame in &self.frames {
+            for (_, value) in &frame.locals {
+                value.trace(&mut roots);
+            }
+        }
+        let frame_tokens = self.frames.iter().flat_map(|f| f.body.iter());
+        for token in frame_tokens.chain(self.scan.iter()) {
+            token.trace(&mut roots);
+        }
+        roots
+    }
+
+    /// Runs the garbage collector, returns the number of freed cells
+    pub(crate) fn collect_garbage(&mut self) -> usize {
+        let roots = self.gc_roots();
+        self.heap.collect(roots)
+    }
+
+    /// Allocates a heap cell, collecting garbage first if needed
+    pub(crate) fn alloc(&mut self, value: Value) -> HeapPointer {
+        if self.heap.should_collect() {
+            let mut roots = self.gc_roots();
+            value.trace(&mut roots);
+            self.heap.collect(roots);
+        }
+        self.heap.alloc(value)
     }
 
     fn push_current_function(&mut self) {
         assert!(!self.in_function());
 
-        self.data.push(Value::Function(self.scan.clone()));
-        self.scan.clear();
+        let namespace = self.scope().clone();
+        let body = self.scan.split_off(0);
+        let mut f = UserFunction::new(namespace, body);
+        if let Some(frame) = self.frames.last() {
+            f.locals = frame.locals.clone();
+        }
+        self.data.push(Value::Function(f));
+    }
+
+    /// Namespace relative names are resolved and defined in, which is
+    /// the definition namespace of the running function if there is one
+    fn scope(&self) -> &AbsoluteSymbolPath {
+        match self.frames.last() {
+            Some(frame) => &frame.namespace,
+            None => &self.current_namespace,
+        }
+    }
+
+    /// Assigns to a name relative to the current scope
+    pub(crate) fn define(&mut self, name: &str, value: Value) -> AbsoluteSymbolPath {
+        let path = SymbolPath::from_str(name).realize(self.scope());
+        self.dict.insert(path.clone(), value);
+        path
     }
 
     fn pop_assign_to(&mut self, name: &str) -> Result<(), Error> {
+        // Such a definition could never be called
+        if parse_literal(name)?.is_some() {
+            return Err(Error::AssignToLiteral(name.to_owned()));
+        }
         let value = self.data.pop().ok_or(Error::StackUndeflow)?;
-        let path = SymbolPath::from_str(name).realize(&self.current_namespace);
-        self.dict.insert(path, value);
+        let path = self.define(name, value);
+        if let Some(doc) = self.pending_doc.take() {
+            self.dict.set_doc(path, doc);
+        }
+        Ok(())
+    }
+
+    /// Consecutive doc comment lines are joined
+    fn add_doc(&mut self, line: String) {
+        self.pending_doc = Some(match self.pending_doc.take() {
+            Some(doc) => format!("{}\n{}", doc, line),
+            None => line,
+        });
+    }
+
+    /// Doc comment of the definition a name resolves to
+    pub fn doc(&self, name: &str) -> Result<Option<String>, Error> {
+        let sp = SymbolPath::from_str(name);
+        match self.resolve(&sp) {
+            Some((path, _)) => Ok(self.dict.doc(&path).map(|d| d.to_owned())),
+            None => Err(Error::NameNotDefined(sp)),
+        }
+    }
+
+    fn end_array(&mut self) -> Result<(), Error> {
+        let mark = self.array_marks.pop().ok_or(Error::ArrayEndOutsideArray)?;
+        if self.data.len() < mark {
+            return Err(Error::StackUndeflow);
+        }
+        let items = self.data.split_off(mark);
+        self.data.push(Value::Array(items));
         Ok(())
     }
 
@@ -74,13 +293,69 @@ impl Interpreter {
         Ok(())
     }
 
+    /// Active frames, innermost last
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    fn push_frame(&mut self, mut frame: Frame) -> Result<(), Error> {
+        // Tail calls replace the frames they were the last token of,
+        // and `return` in them still leaves the replaced function
+        while self.frames.len() > self.call_floor
+            && self.frames.last().unwrap().finished()
+        {
+            let replaced = self.frames.pop().unwrap();
+            frame.returns_here |= replaced.returns_here;
+        }
+        if self.frames.len() >= MAX_CALL_DEPTH {
+            return Err(Error::CallDepthExceeded);
+        }
+        self.frames.push(frame);
+        Ok(())
+    }
+
+    /// Skips the rest of the current function, and of any anonymous
+    /// functions it is running. If the function is waiting for a builtin
+    /// in `call_value`, `Error::Return` unwinds the builtin first.
+    pub(crate) fn return_from_function(&mut self) -> Result<(), Error> {
+        for frame in self.frames[self.call_floor..].iter_mut().rev() {
+            frame.ip = frame.body.len();
+            if frame.returns_here {
+                return Ok(());
+            }
+        }
+        if self.nested_calls > 0 {
+            Err(Error::Return)
+        } else {
+            Ok(())
+        }
+    }
+
     pub(crate) fn execute_value(&mut self, value: Value) -> Result<(), Error> {
+        self.execute_named(None, value)
+    }
+
+    fn execute_named(
+        &mut self,
+        name: Option<AbsoluteSymbolPath>,
+        value: Value,
+    ) -> Result<(), Error> {
         match value {
-            Value::BuiltinFunction(f) => f.call(self),
+            Value::BuiltinFunction(f) => match f.call(self) {
+                // The builtin was running part of the function being left
+                Err(Error::Return) => self.return_from_function(),
+                r => r,
+            },
             Value::Function(f) => {
-                for token in f.iter().rev().cloned() {
-                    self.call.push(token);
-                }
+                self.push_frame(Frame {
+                    returns_here: name.is_some(),
+                    name,
+                    namespace: f.namespace,
+                    body: f.body,
+                    ip: 0,
+                    locals: f.locals,
+                })?;
+                self.data.extend(f.captured);
                 Ok(())
             },
             v => {
@@ -90,105 +365,286 @@ impl Interpreter {
         }
     }
 
+    /// Executes a value, and any code it calls, before returning
+    pub(crate) fn call_value(&mut self, value: Value) -> Result<(), Error> {
+        if self.nested_calls >= MAX_NESTED_CALLS {
+            return Err(Error::CallDepthExceeded);
+        }
+        let depth = self.frames.len();
+        let floor = mem::replace(&mut self.call_floor, depth);
+        self.nested_calls += 1;
+        let r = self
+            .execute_value(value)
+            .and_then(|_| self.run_until(depth));
+        self.nested_calls -= 1;
+        self.call_floor = floor;
+        if let Err(Error::Return) = r {
+            self.frames.truncate(depth);
+        }
+        r
+    }
+
+    /// Steps until only `depth` frames are left
+    fn run_until(&mut self, depth: usize) -> Result<(), Error> {
+        while self.frames.len() > depth {
+            self.step()?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            call_depth: self.frames.len(),
+            array_marks: self.array_marks.len(),
+            pinned: self.pinned.len(),
+        }
+    }
+
+    /// Drops the remaining code of the failed call, the data stack is left as is
+    pub(crate) fn recover(&mut self, cp: Checkpoint) {
+        self.frames.truncate(cp.call_depth);
+        self.array_marks.truncate(cp.array_marks);
+        self.pinned.truncate(cp.pinned);
+        self.skip_next = false;
+        self.skip_array = 0;
+        self.nesting = 0;
+        self.scan.clear();
+        self.pending_doc = None;
+    }
+
+    /// Finds a definition, searching outwards from the current scope
+    fn resolve(&self, sp: &SymbolPath) -> Option<(AbsoluteSymbolPath, Value)> {
+        let rp = sp.clone().realize(self.scope());
+        if let Some(val) = self.dict.resolve(&rp) {
+            return Some((rp, val));
+        } else if let SymbolPath::Relative(ref rsp) = sp {
+            let mut cursor = rp;
+            while let Some(p) = cursor.parent() {
+                let cp = p.join(rsp);
+                if let Some(val) = self.dict.resolve(&cp) {
+                    return Some((cp, val));
+                }
+                cursor = p;
+            }
+        }
+        None
+    }
+
+    pub(crate) fn lookup(&self, name: &str) -> Result<Value, Error> {
+        let sp = SymbolPath::from_str(name);
+        match self.resolve(&sp) {
+            Some((_, val)) => Ok(val),
+            None => Err(Error::NameNotDefined(sp)),
+        }
+    }
+
+    /// Removes a user definition from the current scope, without
+    /// searching outer namespaces
+    pub(crate) fn forget(&mut self, name: &str) -> Result<Value, Error> {
+        let sp = SymbolPath::from_str(name);
+        let path = sp.clone().realize(self.scope());
+        let in_scope = path.parent().as_ref() == Some(self.scope());
+        if !in_scope || self.builtin_names.contains(&path) {
+            return Err(Error::CannotForget(path));
+        }
+        self.dict.remove(&path).ok_or(Error::NameNotDefined(sp))
+    }
+
+    /// Binds the top stack items to the names, the last name to the top item
+    fn bind_locals(&mut self, names: Vec<String>) -> Result<(), Error> {
+        let available = self.data.len();
+        let frame = match self.frames.last_mut() {
+            Some(frame) if frame.ip == 1 => frame,
+            _ => return Err(Error::MisplacedLocals),
+        };
+        if available < names.len() {
+            return Err(Error::NotEnoughArguments(names.len(), available));
+        }
+        let values = self.data.split_off(available - names.len());
+        frame.locals.extend(names.into_iter().zip(values));
+        Ok(())
+    }
+
+    fn local(&self, name: &str) -> Option<Value> {
+        let frame = self.frames.last()?;
+        let (_, value) = frame.locals.iter().rev().find(|(n, _)| n == name)?;
+        Some(value.clone())
+    }
+
     fn execute_ident(&mut self, ident: &str) -> Result<(), Error> {
         // println!("{:<20} |{:?}", ident, self.data);
 
         // Numeric values cannot be overridden
-        if let Ok(int_value) = ident.parse::<u64>() {
-            self.data.push(Value::Integer(int_value));
+        if let Some(value) = parse_literal(ident)? {
+            self.data.push(value);
+            Ok(())
+        } else if let Some(value) = self.local(ident) {
+            self.data.push(value);
             Ok(())
         } else {
             let sp = SymbolPath::from_str(ident);
-            let rp = sp.clone().realize(&self.current_namespace);
-            if let Some(val) = self.dict.resolve(&rp) {
-                return self.execute_value(val);
-            } else if let SymbolPath::Relative(ref rsp) = sp {
-                let mut cursor = rp;
-                while let Some(p) = cursor.parent() {
-                    let cp = p.join(rsp);
-                    if let Some(val) = self.dict.resolve(&cp) {
-                        return self.execute_value(val);
-                    }
-                    cursor = p;
-                }
+            match self.resolve(&sp) {
+                Some((path, val)) => self.execute_named(Some(path), val),
+                None => Err(Error::NameNotDefined(sp)),
             }
-            Err(Error::NameNotDefined(sp))
         }
     }
 
-    fn execute_token(&mut self, token: Token) -> Result<(), Error> {
-        // println!("TOKEN EXEC ({:?})", token);
+    fn execute_token(&mut self, spanned: Spanned) -> Result<(), Error> {
+        // println!("TOKEN EXEC ({:?})", spanned.token);
         if self.in_function() {
-            match token {
-                Token::FunctionStart => self.nesting += 1,
+            match spanned.token {
+                Token::FunctionStart => {
+                    self.nesting += 1;
+                    self.scan.push(spanned);
+                },
                 Token::FunctionEnd => {
                     self.nesting -= 1;
-                    if !self.in_function() {
-                        if self.skip_next {
-                            self.skip_next = false;
-                        } else {
-                            self.push_current_function();
-                        }
+                    if self.in_function() {
+                        self.scan.push(spanned);
+                    } else if self.skip_next {
+                        self.skip_next = false;
+                        self.scan.clear();
+                    } else {
+                        self.push_current_function();
                     }
                 },
-                token => self.scan.push(token),
+                _ => self.scan.push(spanned),
+            };
+            Ok(())
+        } else if self.skip_array > 0 {
+            match spanned.token {
+                Token::ArrayStart => self.skip_array += 1,
+                Token::ArrayEnd => self.skip_array -= 1,
+                _ => {},
             };
             Ok(())
         } else if self.skip_next {
-            match token {
+            match spanned.token {
                 Token::FunctionStart => {
                     self.nesting += 1;
                     Ok(())
                 },
                 Token::FunctionEnd => Err(Error::FunctionEndOutsideFunction),
+                Token::ArrayStart => {
+                    self.skip_next = false;
+                    self.skip_array = 1;
+                    Ok(())
+                },
+                // Comments are not code, skip the token after them
+                Token::Doc(_) => Ok(()),
                 _ => {
                     self.skip_next = false;
+                    self.pending_doc = None;
                     Ok(())
                 },
             }
         } else {
-            match token {
+            match spanned.token {
                 Token::FunctionStart => {
                     self.nesting += 1;
                     Ok(())
                 },
                 Token::FunctionEnd => Err(Error::FunctionEndOutsideFunction),
+                Token::ArrayStart => {
+                    self.array_marks.push(self.data.len());
+                    Ok(())
+                },
+                Token::ArrayEnd => self.end_array(),
                 Token::AssignIdentifier(ident) => self.pop_assign_to(&ident),
                 Token::SetNamespace(ident) => self.set_namespace(&ident),
                 Token::Identifier(ident) => self.execute_ident(&ident),
+                Token::Symbol(name) => {
+                    self.data.push(Value::Symbol(name));
+                    Ok(())
+                },
+                Token::Locals(names) => self.bind_locals(names),
+                Token::Doc(line) => {
+                    self.add_doc(line);
+                    Ok(())
+                },
+                Token::String(text) => {
+                    self.data.push(Value::String(text));
+                    Ok(())
+                },
+                Token::Char(c) => {
+                    self.data.push(Value::String(c.to_string()));
+                    Ok(())
+                },
+                Token::Literal(value) => {
+                    self.data.push(value);
+                    Ok(())
+                },
             }
         }
     }
 
     /// Return true if ready for next for more execute_token calls (outside step)
     pub fn idle(&mut self) -> bool {
-        self.call.is_empty()
+        self.frames.is_empty()
     }
 
     pub fn step(&mut self) -> Result<(), Error> {
-        if let Some(token) = self.call.pop() {
-            self.execute_token(token)
-        } else {
-            Ok(())
+        let token = match self.frames.last_mut() {
+            None => return Ok(()),
+            Some(frame) if frame.finished() => None,
+            Some(frame) => {
+                frame.ip += 1;
+                Some(frame.body[frame.ip - 1].clone())
+            },
+        };
+        match token {
+            Some(token) => self.execute_token(token),
+            None => {
+                self.frames.pop();
+                Ok(())
+            },
         }
     }
 
-    pub fn execute(&mut self, input: &str, _filepath: Option<&str>) -> Result<(), Error> {
-        let mut in_stream = input.chars().peekable();
+    /// Active functions, innermost first
+    fn backtrace(&self) -> Vec<TraceEntry> {
+        self.frames
+            .iter()
+            .rev()
+            .map(|frame| TraceEntry {
+                name: frame.name.clone(),
+                namespace: frame.namespace.clone(),
+                span: frame.span(),
+            })
+            .collect()
+    }
 
-        while in_stream.peek().is_some() {
-            match scan_token(&mut in_stream) {
-                Ok(None) => break,
-                Ok(Some(token)) => {
-                    self.execute_token(token)?;
+    /// Span of the innermost token being executed, if it is in a function
+    pub(crate) fn current_span(&self) -> Option<Span> {
+        self.frames.last()?.span()
+    }
 
-                    while !self.idle() {
-                        self.step()?;
-                    }
-                },
-                Err(e) => {
-                    return Err(Error::InvalidSyntax(e));
-                },
+    /// Executes a token and everything it calls
+    fn run_token(&mut self, token: Spanned) -> Result<(), Error> {
+        self.execute_token(token)?;
+        self.run_until(0)
+    }
+
+    /// Runs parsed code, top-level tokens one at a time
+    pub fn execute_nodes(&mut self, nodes: Vec<Node>) -> Result<(), Failure> {
+        let mut tokens = Vec::new();
+        for node in nodes {
+            node.flatten_into(&mut tokens);
+        }
+
+        for token in tokens {
+            let span = token.span.clone();
+            let cp = self.checkpoint();
+            if let Err(error) = self.run_token(token) {
+                let backtrace = self.backtrace();
+                // Do not leave the rest of the failed code to run later
+                self.recover(cp);
+                return Err(Failure {
+                    error,
+                    backtrace,
+                    span,
+                });
             }
         }
 
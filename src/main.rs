@@ -17,6 +17,8 @@
 #![allow(similar_names)]
 
 extern crate dirs;
+extern crate num_bigint;
+extern crate num_traits;
 extern crate rustyline;
 
 use std::env;
@@ -28,20 +30,30 @@ use rustyline::Editor;
 
 mod builtins;
 mod error;
+mod heap;
 mod interpreter;
 mod namespace;
+mod parser;
 mod scanner;
 mod value;
 
 use std::process::ExitCode;
 
+use crate::error::Diagnostic;
+
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+fn print_diagnostics(diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        println!("{}", diagnostic);
+    }
+}
+
 fn main() -> ExitCode {
     let args: Vec<String> = env::args().collect();
 
     if args.contains(&"-h".to_owned()) || args.contains(&"--help".to_owned()) {
-        println!("Usage: hepta [--version | --help] [filename]");
+        println!("Usage: hepta [--version | --help] [--check] [filename]");
         return ExitCode::SUCCESS;
     }
 
@@ -59,6 +71,27 @@ fn main() -> ExitCode {
     let interactive = fileargs.is_empty()
         || args.contains(&"-i".to_owned())
         || args.contains(&"--interactive".to_owned());
+    let check_only =
+        args.contains(&"-c".to_owned()) || args.contains(&"--check".to_owned());
+
+    if check_only {
+        let mut ok = true;
+        for filepath in &fileargs {
+            let mut f = File::open(filepath).expect("file not found");
+            let mut contents = String::new();
+            f.read_to_string(&mut contents).expect("Could not read");
+
+            if let Err(diagnostics) = parser::parse(&contents, Some(filepath)) {
+                print_diagnostics(&diagnostics);
+                ok = false;
+            }
+        }
+        return if ok {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        };
+    }
 
     let mut interp = interpreter::Interpreter::new().with_builtins();
 
@@ -67,8 +100,15 @@ fn main() -> ExitCode {
         let mut contents = String::new();
         f.read_to_string(&mut contents).expect("Could not read");
 
-        if let Err(error) = interp.execute(&contents.to_owned(), Some(filepath)) {
-            println!("Error: {:?}", error);
+        let nodes = match parser::parse(&contents, Some(filepath)) {
+            Ok(nodes) => nodes,
+            Err(diagnostics) => {
+                print_diagnostics(&diagnostics);
+                return ExitCode::FAILURE;
+            },
+        };
+        if let Err(error) = interp.execute_nodes(nodes) {
+            println!("{}", error);
             return ExitCode::FAILURE;
         }
     }
@@ -96,17 +136,40 @@ fn main() -> ExitCode {
         println!("No previous history.");
     }
 
+    // Lines are collected until the code is balanced
+    let mut pending = String::new();
     loop {
-        match rl.readline(">>> ") {
+        let prompt = if pending.is_empty() { ">>> " } else { "... " };
+        match rl.readline(prompt) {
             Ok(line) => {
                 rl.add_history_entry(line.as_ref());
-
-                if let Err(error) = interp.execute(line.as_ref(), None) {
-                    println!("Error: {:?}", error);
+                pending.push_str(&line);
+                pending.push('\n');
+
+                let nodes = match parser::parse(&pending, None) {
+                    Ok(nodes) => nodes,
+                    Err(ref diagnostics)
+                        if diagnostics.iter().all(|d| d.error.is_incomplete()) =>
+                    {
+                        continue;
+                    },
+                    Err(diagnostics) => {
+                        print_diagnostics(&diagnostics);
+                        pending.clear();
+                        continue;
+                    },
+                };
+
+                pending.clear();
+                if let Err(error) = interp.execute_nodes(nodes) {
+                    println!("{}", error);
                     continue;
                 }
             },
-            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Interrupted) => {
+                pending.clear();
+                continue;
+            },
             Err(ReadlineError::Eof) => break,
             Err(_) => panic!("Unhandled readline error"),
         }
@@ -80,23 +80,41 @@ impl SymbolPath {
 pub struct Namespace {
     /// Only store absolute paths as keys
     values: HashMap<AbsoluteSymbolPath, Value>,
+    /// Doc comments of documented definitions
+    docs: HashMap<AbsoluteSymbolPath, String>,
 }
 impl Namespace {
     pub fn new() -> Self {
         Self {
             values: HashMap::new(),
+            docs: HashMap::new(),
         }
     }
 
+    /// Redefining a name discards its previous documentation
     pub fn insert(&mut self, key: AbsoluteSymbolPath, value: Value) {
+        self.docs.remove(&key);
         self.values.insert(key, value);
     }
 
+    pub fn set_doc(&mut self, key: AbsoluteSymbolPath, doc: String) {
+        self.docs.insert(key, doc);
+    }
+
+    pub fn doc(&self, key: &AbsoluteSymbolPath) -> Option<&str> {
+        self.docs.get(key).map(|d| d.as_str())
+    }
+
     pub fn remove(&mut self, key: &AbsoluteSymbolPath) -> Option<Value> {
+        self.docs.remove(key);
         self.values.remove(key)
     }
 
     pub fn resolve(&self, key: &AbsoluteSymbolPath) -> Option<Value> {
         self.values.get(key).cloned()
     }
+
+    pub fn values(&self) -> impl Iterator<Item = &Value> {
+        self.values.values()
+    }
 }
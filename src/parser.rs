@@ -0,0 +1,191 @@
+//! Whole-program parsing, so that unbalanced code is rejected before any of it runs
+
+use crate::error::{Diagnostic, SyntaxError};
+use crate::scanner::{scan_token, Source, Spanned, Token};
+
+/// Token or a balanced block, blocks keep their delimiter tokens
+#[derive(Debug, Clone)]
+pub enum Node {
+    Token(Spanned),
+    /// Function(start, body, end)
+    Function(Spanned, Vec<Node>, Spanned),
+    /// Array(start, items, end)
+    Array(Spanned, Vec<Node>, Spanned),
+}
+impl Node {
+    /// Appends the tokens of this node in source order
+    pub fn flatten_into(self, tokens: &mut Vec<Spanned>) {
+        match self {
+            Node::Token(token) => tokens.push(token),
+            Node::Function(start, body, end) | Node::Array(start, body, end) => {
+                tokens.push(start);
+                for node in body {
+                    node.flatten_into(tokens);
+                }
+                tokens.push(end);
+            },
+        }
+    }
+}
+
+fn is_doc(node: &Node) -> bool {
+    match node {
+        Node::Token(Spanned {
+            token: Token::Doc(_),
+            ..
+        }) => true,
+        _ => false,
+    }
+}
+
+fn is_assign(node: Option<&Node>) -> bool {
+    match node {
+        Some(Node::Token(Spanned {
+            token: Token::AssignIdentifier(_),
+            ..
+        })) => true,
+        _ => false,
+    }
+}
+
+/// Moves doc comments to right before the `/name` they document, so that
+/// running the value in between cannot pick them up. Doc comments that are
+/// not followed by a definition, `value /name` or just `/name`, are dropped.
+fn place_docs(nodes: Vec<Node>) -> Vec<Node> {
+    let mut placed = Vec::with_capacity(nodes.len());
+    let mut docs = Vec::new();
+    let mut nodes = nodes.into_iter().peekable();
+    while let Some(node) = nodes.next() {
+        if is_doc(&node) {
+            docs.push(node);
+            continue;
+        }
+        if is_assign(Some(&node)) {
+            placed.append(&mut docs);
+            placed.push(node);
+            continue;
+        }
+
+        placed.push(match node {
+            Node::Function(start, body, end) => {
+                Node::Function(start, place_docs(body), end)
+            },
+            Node::Array(start, items, end) => Node::Array(start, place_docs(items), end),
+            token => token,
+        });
+        if is_assign(nodes.peek()) {
+            placed.append(&mut docs);
+        } else {
+            docs.clear();
+        }
+    }
+    placed
+}
+
+/// Block opened but not yet closed
+struct OpenBlock {
+    start: Spanned,
+    body: Vec<Node>,
+}
+impl OpenBlock {
+    fn unclosed(&self) -> Diagnostic {
+        let error = match self.start.token {
+            Token::FunctionStart => SyntaxError::UnclosedFunction,
+            _ => SyntaxError::UnclosedArray,
+        };
+        Diagnostic {
+            error,
+            span: self.start.span.clone(),
+        }
+    }
+}
+
+/// Closes the block that `end` belongs to. A closer of the other kind than
+/// the innermost block is reported, and then closes the nearest block of its
+/// kind, whose unclosed inner blocks are kept as plain tokens in it.
+fn close(
+    open: &mut Vec<OpenBlock>,
+    end: Spanned,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Option<Node> {
+    let start = match end.token {
+        Token::FunctionEnd => Token::FunctionStart,
+        _ => Token::ArrayStart,
+    };
+    let innermost = match open.last() {
+        Some(block) => &block.start.token,
+        None => {
+            let error = match end.token {
+                Token::FunctionEnd => SyntaxError::UnmatchedFunctionEnd,
+                _ => SyntaxError::UnmatchedArrayEnd,
+            };
+            diagnostics.push(Diagnostic {
+                error,
+                span: end.span,
+            });
+            return None;
+        },
+    };
+    if *innermost != start {
+        diagnostics.push(Diagnostic {
+            error: SyntaxError::MismatchedDelimiter,
+            span: end.span.clone(),
+        });
+        if !open.iter().any(|block| block.start.token == start) {
+            return None;
+        }
+    }
+
+    loop {
+        let block = open.pop().expect("Matching block is open");
+        if block.start.token == start {
+            return Some(match start {
+                Token::FunctionStart => Node::Function(block.start, block.body, end),
+                _ => Node::Array(block.start, block.body, end),
+            });
+        }
+        let parent = open.last_mut().expect("Matching block is open");
+        parent.body.push(Node::Token(block.start));
+        parent.body.extend(block.body);
+    }
+}
+
+/// Builds the tree of a whole program without executing anything.
+/// Parsing continues after errors, so that all of them are reported.
+pub fn parse(input: &str, filepath: Option<&str>) -> Result<Vec<Node>, Vec<Diagnostic>> {
+    let mut in_stream = Source::new(input, filepath);
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let mut open: Vec<OpenBlock> = Vec::new();
+    let mut top: Vec<Node> = Vec::new();
+
+    while let Some(token) = scan_token(&mut in_stream, &mut diagnostics) {
+        let node = match token.token {
+            Token::FunctionStart | Token::ArrayStart => {
+                open.push(OpenBlock {
+                    start: token,
+                    body: Vec::new(),
+                });
+                continue;
+            },
+            Token::FunctionEnd | Token::ArrayEnd => {
+                match close(&mut open, token, &mut diagnostics) {
+                    Some(node) => node,
+                    None => continue,
+                }
+            },
+            _ => Node::Token(token),
+        };
+
+        match open.last_mut() {
+            Some(block) => block.body.push(node),
+            None => top.push(node),
+        }
+    }
+
+    diagnostics.extend(open.iter().map(OpenBlock::unclosed));
+    if diagnostics.is_empty() {
+        Ok(place_docs(top))
+    } else {
+        Err(diagnostics)
+    }
+}
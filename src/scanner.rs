@@ -1,15 +1,128 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::iter::Peekable;
+use std::rc::Rc;
 use std::str::Chars;
 
-use crate::error::SyntaxError;
+use crate::error::{Diagnostic, SyntaxError};
+use crate::value::Value;
+
+/// Position in the source text, lines and columns start from 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Range of source text, `end` is just past the last character
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    /// `None` for interactive input
+    pub file: Option<Rc<String>>,
+    pub start: Location,
+    pub end: Location,
+}
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}", file, self.start),
+            None => write!(f, "{}", self.start),
+        }
+    }
+}
+
+/// Characters of the source text, tracking the location of the next one
+pub(crate) struct Source<'a> {
+    chars: Peekable<Chars<'a>>,
+    file: Option<Rc<String>>,
+    next: Location,
+}
+impl<'a> Source<'a> {
+    pub fn new(text: &'a str, file: Option<&str>) -> Self {
+        Self {
+            chars: text.chars().peekable(),
+            file: file.map(|f| Rc::new(f.to_owned())),
+            next: Location { line: 1, column: 1 },
+        }
+    }
+
+    /// Span from `start` to the next character
+    pub fn span_from(&self, start: Location) -> Span {
+        Span {
+            file: self.file.clone(),
+            start,
+            end: self.next,
+        }
+    }
+
+    pub fn location(&self) -> Location {
+        self.next
+    }
+
+    pub fn peek(&mut self) -> Option<&char> {
+        self.chars.peek()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.next.line += 1;
+            self.next.column = 1;
+        } else {
+            self.next.column += 1;
+        }
+        Some(c)
+    }
+}
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum Token {
     Identifier(String),
     AssignIdentifier(String),
     SetNamespace(String),
+    Symbol(String),
+    /// Names to bind the top stack items to, at the start of a function
+    Locals(Vec<String>),
+    /// Line of a `;;` comment, documenting the next `/name` definition
+    Doc(String),
+    String(String),
+    /// Pushed as a one-character string, like the items `each` gives for strings
+    Char(char),
     FunctionStart,
     FunctionEnd,
+    ArrayStart,
+    ArrayEnd,
+    /// Value embedded into a function by `curry`, never scanned
+    Literal(Value),
+}
+
+/// Token and where it came from, `span` is `None` for generated tokens.
+/// Spans are ignored when comparing, so equal code compares equal.
+#[derive(Debug, Clone)]
+pub struct Spanned {
+    pub token: Token,
+    pub span: Option<Span>,
+}
+impl From<Token> for Spanned {
+    fn from(token: Token) -> Self {
+        Self { token, span: None }
+    }
+}
+impl PartialEq for Spanned {
+    fn eq(&self, other: &Self) -> bool {
+        self.token == other.token
+    }
+}
+impl Eq for Spanned {}
+impl Hash for Spanned {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.token.hash(state);
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,30 +132,80 @@ enum TokenScanAction {
     DoneContinueHere,
 }
 
+/// Character written after a backslash in string and character literals
+fn unescape(c: char) -> Result<char, SyntaxError> {
+    Ok(match c {
+        'n' => '\n',
+        't' => '\t',
+        'r' => '\r',
+        '0' => '\0',
+        '\\' => '\\',
+        '"' => '"',
+        '\'' => '\'',
+        _ => return Err(SyntaxError::UnknownEscape(c)),
+    })
+}
+
+/// Characters that terminate identifiers and names
+pub(crate) fn ends_word(c: char) -> bool {
+    c.is_whitespace() || c == '{' || c == '}' || c == '[' || c == ']'
+}
+
 #[derive(Debug, Clone)]
 enum TokenScanState {
     /// Comment(nesting_level)
-    Comment(u8),
+    Comment(usize),
+    /// Semicolon read, either a line comment or a doc comment follows
+    CommentStart,
+    /// Comment running until the end of the line
+    LineComment,
+    /// DocComment(so_far)
+    DocComment(String),
     /// Identifier(so_far)
     Identifier(String),
     /// AssignIdentifier(so_far)
     AssignIdentifier(String),
     /// SetNamespace(so_far)
     SetNamespace(String),
+    /// StringLiteral(so_far)
+    StringLiteral(String),
+    /// StringEscape(so_far), i.e. backslash read inside a string literal
+    StringEscape(String),
+    /// Quoted name, pushed without being executed
+    Symbol(String),
+    /// Locals(complete_names, current_name)
+    Locals(Vec<String>, String),
+    /// CharLiteral(so_far)
+    CharLiteral(String),
+    /// CharEscape(so_far), i.e. backslash read inside a character literal
+    CharEscape(String),
     /// Start of function definition
     FunctionStart,
     /// End of function definition
     FunctionEnd,
+    /// Start of array literal
+    ArrayStart,
+    /// End of array literal
+    ArrayEnd,
+    /// Rejected token, skipped after reporting it
+    Invalid,
 }
 impl TokenScanState {
     fn scan_first(c: char) -> Self {
         use self::TokenScanState::*;
         match c {
             '(' => Comment(0),
+            ';' => CommentStart,
             '{' => FunctionStart,
             '}' => FunctionEnd,
+            '[' => ArrayStart,
+            ']' => ArrayEnd,
             '/' => AssignIdentifier(String::new()),
             '#' => SetNamespace(String::new()),
+            '"' => StringLiteral(String::new()),
+            '\'' => CharLiteral(String::new()),
+            '\\' => Symbol(String::new()),
+            '|' => Locals(Vec::new(), String::new()),
             chr => Identifier(chr.to_string()),
         }
     }
@@ -61,74 +224,187 @@ impl TokenScanState {
                 '(' => Ok((Comment(cmt + 1), Continue)),
                 _ => Ok((Comment(cmt), Continue)),
             },
-            Identifier(ident) => if c.is_whitespace() || c == '{' || c == '}' {
+            CommentStart => match c {
+                ';' => Ok((DocComment(String::new()), Continue)),
+                '\n' => Ok((LineComment, DoneConsumeThis)),
+                _ => Ok((LineComment, Continue)),
+            },
+            LineComment => match c {
+                '\n' => Ok((LineComment, DoneConsumeThis)),
+                _ => Ok((LineComment, Continue)),
+            },
+            DocComment(text) => match c {
+                '\n' => Ok((self, DoneConsumeThis)),
+                _ => Ok((DocComment(format!("{}{}", text, c)), Continue)),
+            },
+            Identifier(ident) => if ends_word(c) {
                 Ok((self, DoneContinueHere))
             } else {
                 Ok((Identifier(format!("{}{}", ident, c)), Continue))
             },
-            AssignIdentifier(ident) => if c.is_whitespace() || c == '{' || c == '}' {
-                if ident.is_empty() {
-                    Err(SyntaxError::AssignToEmpty)
-                } else {
-                    Ok((self, DoneContinueHere))
-                }
+            AssignIdentifier(ident) => if ends_word(c) {
+                Ok((self, DoneContinueHere))
             } else {
                 Ok((AssignIdentifier(format!("{}{}", ident, c)), Continue))
             },
-            SetNamespace(ident) => if c.is_whitespace() || c == '{' || c == '}' {
+            SetNamespace(ident) => if ends_word(c) {
                 Ok((self, DoneContinueHere))
             } else {
                 Ok((SetNamespace(format!("{}{}", ident, c)), Continue))
             },
+            Symbol(name) => if ends_word(c) {
+                Ok((self, DoneContinueHere))
+            } else {
+                Ok((Symbol(format!("{}{}", name, c)), Continue))
+            },
+            Locals(mut names, name) => if c == '|' || c.is_whitespace() {
+                if !name.is_empty() {
+                    names.push(name);
+                }
+                if c == '|' {
+                    Ok((Locals(names, String::new()), DoneConsumeThis))
+                } else {
+                    Ok((Locals(names, String::new()), Continue))
+                }
+            } else if ends_word(c) || c == ':' || c == '\\' {
+                Err(SyntaxError::InvalidLocalName(format!("{}{}", name, c)))
+            } else {
+                Ok((Locals(names, format!("{}{}", name, c)), Continue))
+            },
+            StringLiteral(text) => match c {
+                '"' => Ok((self, DoneConsumeThis)),
+                '\\' => Ok((StringEscape(text), Continue)),
+                _ => Ok((StringLiteral(format!("{}{}", text, c)), Continue)),
+            },
+            StringEscape(text) => {
+                let escaped = unescape(c)?;
+                Ok((StringLiteral(format!("{}{}", text, escaped)), Continue))
+            },
+            CharLiteral(text) => match c {
+                '\'' => if text.chars().count() == 1 {
+                    Ok((self, DoneConsumeThis))
+                } else {
+                    Err(SyntaxError::InvalidCharLiteral(text))
+                },
+                '\\' => Ok((CharEscape(text), Continue)),
+                _ => Ok((CharLiteral(format!("{}{}", text, c)), Continue)),
+            },
+            CharEscape(text) => {
+                let escaped = unescape(c)?;
+                Ok((CharLiteral(format!("{}{}", text, escaped)), Continue))
+            },
             FunctionStart => Ok((FunctionStart, DoneContinueHere)),
             FunctionEnd => Ok((FunctionEnd, DoneContinueHere)),
+            ArrayStart => Ok((ArrayStart, DoneContinueHere)),
+            ArrayEnd => Ok((ArrayEnd, DoneContinueHere)),
+            Invalid => Ok((Invalid, DoneContinueHere)),
         }
     }
 
-    fn to_token(&self) -> Option<Token> {
+    /// Continues after `scan_join` rejected `c`, dropping what cannot be used
+    fn recover(self, c: char) -> (Self, TokenScanAction) {
+        use self::TokenScanAction::*;
         use self::TokenScanState::*;
+
         match self {
-            Comment(_) => None,
-            Identifier(ident) => Some(Token::Identifier(ident.clone())),
-            AssignIdentifier(ident) => Some(Token::AssignIdentifier(ident.clone())),
-            SetNamespace(ident) => Some(Token::SetNamespace(ident.clone())),
+            StringEscape(text) => (StringLiteral(format!("{}{}", text, c)), Continue),
+            CharEscape(text) => (CharLiteral(format!("{}{}", text, c)), Continue),
+            CharLiteral(_) => (Invalid, DoneConsumeThis),
+            Locals(names, name) => (Locals(names, name), Continue),
+            _ => (Invalid, DoneContinueHere),
+        }
+    }
+
+    fn to_token(&self) -> Result<Option<Token>, SyntaxError> {
+        use self::TokenScanState::*;
+        Ok(match self {
+            Comment(_) | CommentStart | LineComment | Invalid => None,
+            DocComment(text) => Some(Token::Doc(text.trim().to_owned())),
+            Identifier(ident) => {
+                check_path(ident)?;
+                Some(Token::Identifier(ident.clone()))
+            },
+            AssignIdentifier(ident) => {
+                if ident.is_empty() {
+                    return Err(SyntaxError::AssignToEmpty);
+                }
+                check_path(ident)?;
+                Some(Token::AssignIdentifier(ident.clone()))
+            },
+            SetNamespace(ident) => {
+                if ident.is_empty() {
+                    return Err(SyntaxError::EmptyNamespace);
+                }
+                check_path(ident)?;
+                Some(Token::SetNamespace(ident.clone()))
+            },
+            StringLiteral(text) => Some(Token::String(text.clone())),
+            StringEscape(_) => None,
+            CharLiteral(text) => text.chars().next().map(Token::Char),
+            CharEscape(_) => None,
+            Symbol(name) => {
+                if name.is_empty() {
+                    return Err(SyntaxError::EmptySymbol);
+                }
+                check_path(name)?;
+                Some(Token::Symbol(name.clone()))
+            },
+            Locals(names, _) => Some(Token::Locals(names.clone())),
             FunctionStart => Some(Token::FunctionStart),
             FunctionEnd => Some(Token::FunctionEnd),
-        }
+            ArrayStart => Some(Token::ArrayStart),
+            ArrayEnd => Some(Token::ArrayEnd),
+        })
+    }
+}
+
+/// Paths are names separated by `::`, absolute ones start with `::`
+fn check_path(path: &str) -> Result<(), SyntaxError> {
+    let relative = if path.starts_with("::") {
+        &path[2..]
+    } else {
+        path
+    };
+    if relative.split("::").any(|field| field.is_empty()) {
+        Err(SyntaxError::MalformedPath(path.to_owned()))
+    } else {
+        Ok(())
     }
 }
 
+/// Scans the next token, recording syntax errors and skipping past them
 fn scan_one_token(
-    input: &mut Peekable<Chars<'_>>,
-) -> Result<Option<TokenScanState>, SyntaxError> {
+    input: &mut Source<'_>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Option<(Location, TokenScanState)> {
     use self::TokenScanAction::*;
 
-    let mut state: Option<TokenScanState> = None;
-
-    while {
-        match input.peek() {
-            None => if state.is_none() {
-                return Ok(None);
-            } else {
-                return Err(SyntaxError::UnexpectedEndOfInput);
-            },
-            Some(s) => s.is_whitespace(),
-        }
-    } {
+    while input.peek()?.is_whitespace() {
         input.next();
     }
+    let start = input.location();
 
+    let mut state: Option<TokenScanState> = None;
     while let Some(&c) = input.peek() {
         // println!("> {:?} : {:?}", c, state.clone());
         state = Some(if let Some(s) = state.clone() {
-            let (new_state, action) = s.scan_join(c)?;
+            let (new_state, action) = match s.clone().scan_join(c) {
+                Ok(joined) => joined,
+                Err(error) => {
+                    diagnostics.push(Diagnostic {
+                        error,
+                        span: Some(input.span_from(start)),
+                    });
+                    s.recover(c)
+                },
+            };
 
             if action != DoneContinueHere {
                 input.next();
             }
 
             if action != Continue {
-                return Ok(Some(new_state));
+                return Some((start, new_state));
             }
 
             new_state
@@ -138,21 +414,57 @@ fn scan_one_token(
         });
     }
 
-    match state {
-        Some(s) => Ok(Some(s)),
-        None => Err(SyntaxError::UnexpectedEndOfInput),
+    let error = match state {
+        Some(TokenScanState::Comment(_)) => SyntaxError::UnterminatedComment,
+        Some(TokenScanState::StringLiteral(_))
+        | Some(TokenScanState::StringEscape(_))
+        | Some(TokenScanState::CharLiteral(_))
+        | Some(TokenScanState::CharEscape(_))
+        | Some(TokenScanState::Locals(_, _)) => SyntaxError::UnexpectedEndOfInput,
+        Some(s) => return Some((start, s)),
+        None => return None,
+    };
+    diagnostics.push(Diagnostic {
+        error,
+        span: Some(input.span_from(start)),
+    });
+    None
+}
+
+/// Whether `name` scans as exactly this one identifier,
+/// i.e. code can refer to definitions made under it
+pub(crate) fn is_identifier(name: &str) -> bool {
+    let mut input = Source::new(name, None);
+    let mut diagnostics = Vec::new();
+    let first = scan_token(&mut input, &mut diagnostics).map(|t| t.token);
+    let rest = scan_token(&mut input, &mut diagnostics);
+    match first {
+        Some(Token::Identifier(ident)) => {
+            ident == name && rest.is_none() && diagnostics.is_empty()
+        },
+        _ => false,
     }
 }
 
+/// Next valid token, syntax errors before it are added to `diagnostics`
 pub(crate) fn scan_token(
-    mut input: &mut Peekable<Chars<'_>>,
-) -> Result<Option<Token>, SyntaxError> {
+    input: &mut Source<'_>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Option<Spanned> {
     loop {
-        let x: Option<TokenScanState> = scan_one_token(&mut input)?;
-        return match x.map(|ts| ts.to_token()) {
-            Some(Some(t)) => Ok(Some(t)),
-            Some(None) => Ok(None),
-            None => Ok(None),
-        };
+        let (start, state) = scan_one_token(input, diagnostics)?;
+        match state.to_token() {
+            Ok(Some(token)) => {
+                return Some(Spanned {
+                    token,
+                    span: Some(input.span_from(start)),
+                })
+            },
+            Ok(None) => continue,
+            Err(error) => diagnostics.push(Diagnostic {
+                error,
+                span: Some(input.span_from(start)),
+            }),
+        }
     }
 }
@@ -1,12 +1,18 @@
 #![allow(stutter)]
 
 use std::cmp::PartialEq;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::mem;
+
+use num_bigint::BigUint;
 
 use crate::error::Error;
 use crate::interpreter::Interpreter;
-use crate::scanner::Token;
+use crate::namespace::AbsoluteSymbolPath;
+use crate::scanner::Spanned;
 
 #[derive(Clone)]
 pub struct BuiltinFunction {
@@ -54,26 +60,77 @@ pub struct HeapPointer(pub usize);
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub struct StructDefinitionIndex(pub usize);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum ValueType {
     Boolean,
     Index,
     Integer,
+    SignedInteger,
+    BigInteger,
+    Float,
+    String,
+    Symbol,
+    Array,
+    Map,
+    Error,
+    Type,
     Pointer,
     Function,
     BuiltinFunction,
     UserDefined,
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+impl ValueType {
+    /// Every type, named in the language by their `Debug` names
+    pub const ALL: [ValueType; 16] = [
+        ValueType::Boolean,
+        ValueType::Index,
+        ValueType::Integer,
+        ValueType::SignedInteger,
+        ValueType::BigInteger,
+        ValueType::Float,
+        ValueType::String,
+        ValueType::Symbol,
+        ValueType::Array,
+        ValueType::Map,
+        ValueType::Error,
+        ValueType::Type,
+        ValueType::Pointer,
+        ValueType::Function,
+        ValueType::BuiltinFunction,
+        ValueType::UserDefined,
+    ];
+
+    /// Type of the given name, see `ALL`
+    pub fn from_name(name: &str) -> Option<Self> {
+        ValueType::ALL
+            .iter()
+            .cloned()
+            .find(|ttag| format!("{:?}", ttag) == name)
+    }
+}
+
+#[derive(Debug, Clone)]
 pub enum Value {
     Boolean(bool),
     Index(usize),
     Integer(u64),
+    SignedInteger(i64),
+    /// Only used for values that do not fit in `Integer`
+    BigInteger(BigUint),
+    Float(f64),
+    String(String),
+    /// Name as written, resolved only when used
+    Symbol(String),
+    Array(Vec<Value>),
+    Map(ValueMap),
+    /// Runtime error caught by `try`, Error(kind, message)
+    Error(String, String),
+    Type(ValueType),
     Pointer(HeapPointer),
-    Function(Vec<Token>),
+    Function(UserFunction),
     BuiltinFunction(BuiltinFunction),
-    UserDefined(StructDefinitionIndex),
+    UserDefined(UserStruct),
 }
 impl Value {
     pub fn type_(&self) -> ValueType {
@@ -83,36 +140,148 @@ impl Value {
             Boolean(_) => ValueType::Boolean,
             Index(_) => ValueType::Index,
             Integer(_) => ValueType::Integer,
+            SignedInteger(_) => ValueType::SignedInteger,
+            BigInteger(_) => ValueType::BigInteger,
+            Float(_) => ValueType::Float,
+            String(_) => ValueType::String,
+            Symbol(_) => ValueType::Symbol,
+            Array(_) => ValueType::Array,
+            Map(_) => ValueType::Map,
+            Error(_, _) => ValueType::Error,
+            Type(_) => ValueType::Type,
             Pointer(_) => ValueType::Pointer,
             Function(_) => ValueType::Function,
             BuiltinFunction(_) => ValueType::BuiltinFunction,
             UserDefined(_) => ValueType::UserDefined,
         }
     }
+
+    /// Equality as seen by scripts: floats compare as IEEE numbers, so
+    /// `0.0` equals `-0.0` and NaN equals nothing. Map keys still use
+    /// the bitwise `PartialEq`.
+    pub fn numeric_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Float(a), Value::Float(b)) => a == b,
+            (Value::Array(a), Value::Array(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.numeric_eq(y))
+            },
+            _ => self == other,
+        }
+    }
 }
+/// Floats are compared bitwise, so that every value equals itself
+/// and equal values always have equal hashes
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        use self::Value::*;
 
+        match (self, other) {
+            (Boolean(a), Boolean(b)) => a == b,
+            (Index(a), Index(b)) => a == b,
+            (Integer(a), Integer(b)) => a == b,
+            (SignedInteger(a), SignedInteger(b)) => a == b,
+            (BigInteger(a), BigInteger(b)) => a == b,
+            (Float(a), Float(b)) => a.to_bits() == b.to_bits(),
+            (String(a), String(b)) => a == b,
+            (Symbol(a), Symbol(b)) => a == b,
+            (Array(a), Array(b)) => a == b,
+            (Map(a), Map(b)) => a == b,
+            (Error(a, b), Error(c, d)) => a == c && b == d,
+            (Type(a), Type(b)) => a == b,
+            (Pointer(a), Pointer(b)) => a == b,
+            (Function(a), Function(b)) => a == b,
+            (BuiltinFunction(a), BuiltinFunction(b)) => a == b,
+            (UserDefined(a), UserDefined(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+impl Eq for Value {}
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        use self::Value::*;
+
+        mem::discriminant(self).hash(state);
+        match self {
+            Boolean(v) => v.hash(state),
+            Index(v) => v.hash(state),
+            Integer(v) => v.hash(state),
+            SignedInteger(v) => v.hash(state),
+            BigInteger(v) => v.hash(state),
+            Float(v) => v.to_bits().hash(state),
+            String(v) => v.hash(state),
+            Symbol(v) => v.hash(state),
+            Array(v) => v.hash(state),
+            Map(v) => v.hash(state),
+            Error(kind, message) => {
+                kind.hash(state);
+                message.hash(state);
+            },
+            Type(v) => v.hash(state),
+            Pointer(v) => v.hash(state),
+            Function(v) => v.hash(state),
+            BuiltinFunction(v) => v.hash(state),
+            UserDefined(v) => v.hash(state),
+        }
+    }
+}
+
+/// Hash map from values to values
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValueMap(pub HashMap<Value, Value>);
+/// Iteration order is unspecified, so entries are hashed separately
+/// and combined with a commutative operation
+impl Hash for ValueMap {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut combined: u64 = 0;
+        for (key, value) in &self.0 {
+            let mut entry = DefaultHasher::new();
+            key.hash(&mut entry);
+            value.hash(&mut entry);
+            combined = combined.wrapping_add(entry.finish());
+        }
+        self.0.len().hash(state);
+        combined.hash(state);
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct UserStructMetaField {
     pub name: String,
-    pub size: usize,
     pub ttag: ValueType,
 }
 
+#[derive(Debug, Clone)]
 pub struct UserStructMeta {
+    pub name: String,
     pub fields: Vec<UserStructMetaField>,
 }
-impl UserStructMeta {
-    /// Size, in bytes
-    pub fn size(&self) -> usize {
-        self.fields.iter().map(|item| item.size).sum()
-    }
-}
 
-struct UserStruct {
-    meta: UserStructMeta,
-    data: Vec<u8>,
+/// Instance of a user-defined struct, with one value per field
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct UserStruct {
+    pub definition: StructDefinitionIndex,
+    pub fields: Vec<Value>,
 }
 
-struct UserFunction {
-    meta: UserStructMeta,
-    data: Vec<u8>,
+/// Function literal, remembering where it was defined
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct UserFunction {
+    /// Relative names in `body` are resolved from here
+    pub namespace: AbsoluteSymbolPath,
+    pub body: Vec<Spanned>,
+    /// Values captured by `closure`, pushed before `body` runs
+    pub captured: Vec<Value>,
+    /// Locals of the function this one was created in
+    pub locals: Vec<(String, Value)>,
+}
+impl UserFunction {
+    pub fn new(namespace: AbsoluteSymbolPath, body: Vec<Spanned>) -> Self {
+        Self {
+            namespace,
+            body,
+            captured: Vec::new(),
+            locals: Vec::new(),
+        }
+    }
 }